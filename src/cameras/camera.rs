@@ -1,37 +1,102 @@
-use crate::primitives::{Canvas, Point, Ray, SquareMatrix, Vector};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::primitives::{Point, Ray, SquareMatrix, Vector};
+
+use super::{PerspectiveProjection, Projection};
 
 pub struct Camera {
     height: usize,
     width: usize,
     transform: SquareMatrix,
     inverted: SquareMatrix,
-    half_width: f64,
-    half_height: f64,
-    pixel_size: f64,
+    projection: Box<dyn Projection>,
+    aspect: Option<f64>,
+    aperture: f64,
+    focal_distance: f64,
 }
 
 impl Camera {
-    pub fn new(canvas: &Canvas, fov_in_radians: f64) -> Self {
-        let half_view = (fov_in_radians / 2.0).tan();
-        let aspect = canvas.get_width() as f64 / canvas.get_height() as f64;
-        let half_width;
-        let half_height;
-        if aspect >= 1.0 {
-            half_width = half_view;
-            half_height = half_view / aspect;
-        } else {
-            half_width = half_view * aspect;
-            half_height = half_view;
-        }
-        let pixel_size = half_width * 2.0 / canvas.get_width() as f64;
+    /// Creates a perspective camera for a `width` by `height` image; the field
+    /// of view spans the longer side and the aspect follows the resolution
+    /// until `set_aspect` fixes it.
+    pub fn new(width: usize, height: usize, fov_in_radians: f64) -> Self {
+        Camera::with_projection(width, height, PerspectiveProjection::new(fov_in_radians))
+    }
+
+    pub fn with_projection<T: Projection + 'static>(
+        width: usize,
+        height: usize,
+        projection: T,
+    ) -> Self {
         Camera {
-            height: canvas.get_height(),
-            width: canvas.get_width(),
+            height: height.max(1),
+            width: width.max(1),
             transform: SquareMatrix::identity(4),
             inverted: SquareMatrix::identity(4),
-            half_height,
-            half_width,
-            pixel_size,
+            projection: Box::new(projection),
+            aspect: None,
+            aperture: 0.0,
+            focal_distance: 1.0,
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// The width of the image over its height.
+    pub fn get_aspect(&self) -> f64 {
+        self.aspect
+            .unwrap_or(self.width as f64 / self.height as f64)
+    }
+
+    /// Fixes the aspect independently of the resolution, which renders
+    /// non-square pixels; `None` makes it follow the resolution again.
+    /// Aspects that are not positive and finite are ignored.
+    pub fn set_aspect(&mut self, aspect: Option<f64>) {
+        if aspect.is_none_or(|aspect| aspect > 0.0 && aspect.is_finite()) {
+            self.aspect = aspect;
+        }
+    }
+
+    /// Changes the resolution while keeping the position, projection, aspect
+    /// and lens. Both dimensions are kept at least one pixel wide.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.width = width.max(1);
+        self.height = height.max(1);
+    }
+
+    pub fn get_projection(&self) -> &dyn Projection {
+        self.projection.as_ref()
+    }
+
+    pub fn set_projection<T: Projection + 'static>(&mut self, projection: T) {
+        self.projection = Box::new(projection);
+    }
+
+    pub fn get_aperture(&self) -> f64 {
+        self.aperture
+    }
+
+    /// Sets the lens radius; zero keeps the camera a pinhole.
+    pub fn set_aperture(&mut self, aperture: f64) {
+        self.aperture = aperture.max(0.0);
+    }
+
+    pub fn get_focal_distance(&self) -> f64 {
+        self.focal_distance
+    }
+
+    /// Sets the distance from the lens to the plane that stays in focus.
+    /// Distances that are not positive are ignored, since they would put the
+    /// focal plane on or behind the lens.
+    pub fn set_focal_distance(&mut self, focal_distance: f64) {
+        if focal_distance > 0.0 {
+            self.focal_distance = focal_distance;
         }
     }
 
@@ -58,19 +123,78 @@ impl Camera {
         self.inverted = self.transform.invert();
     }
 
-    pub fn get_ray(&self, x: usize, y: usize) -> Ray {
-        let x = x.clamp(0, self.width) as f64;
-        let y = y.clamp(0, self.height) as f64;
+    pub fn get_ray(&self, x: usize, y: usize) -> Option<Ray> {
+        self.get_sample_ray(x, y, 0.5, 0.5)
+    }
+
+    /// Shoots a pinhole ray through a point given in pixel units, where `(0, 0)`
+    /// is the top left corner of the image and `(width, height)` the bottom
+    /// right one. Points outside the image are clamped to its border.
+    pub fn get_ray_at(&self, x: f64, y: f64) -> Option<Ray> {
+        self.get_lens_ray_at(x, y, 0.5, 0.5)
+    }
+
+    pub fn get_sample_ray(&self, x: usize, y: usize, offset_x: f64, offset_y: f64) -> Option<Ray> {
+        self.get_lens_ray(x, y, offset_x, offset_y, 0.5, 0.5)
+    }
+
+    /// Shoots a ray from a point on the lens, chosen by `lens_u` and `lens_v`
+    /// in `[0, 1)`, through the focal plane where the pinhole ray for the same
+    /// sub-pixel position would cross it. Rays that do not head forward are
+    /// left unblurred. Returns `None` where the projection does not cover the
+    /// image.
+    pub fn get_lens_ray(
+        &self,
+        x: usize,
+        y: usize,
+        offset_x: f64,
+        offset_y: f64,
+        lens_u: f64,
+        lens_v: f64,
+    ) -> Option<Ray> {
+        let x = x.min(self.width - 1) as f64;
+        let y = y.min(self.height - 1) as f64;
+        self.get_lens_ray_at(x + offset_x, y + offset_y, lens_u, lens_v)
+    }
 
-        let x_offset = (x + 0.5) * self.pixel_size;
-        let y_offset = (y + 0.5) * self.pixel_size;
+    /// Like `get_ray_at`, but shoots the ray from the point on the lens chosen
+    /// by `lens_u` and `lens_v`.
+    pub fn get_lens_ray_at(&self, x: f64, y: f64, lens_u: f64, lens_v: f64) -> Option<Ray> {
+        let u = x.clamp(0.0, self.width as f64) / self.width as f64;
+        let v = y.clamp(0.0, self.height as f64) / self.height as f64;
+        let ray = self.projection.get_camera_ray(u, v, self.get_aspect())?;
 
-        let world_x = self.half_width - x_offset;
-        let world_y = self.half_height - y_offset;
+        let mut origin = ray.get_origin().clone();
+        let mut target = ray.get_origin() + ray.get_direction();
+        let forward = -ray.get_direction().get_z();
+        if self.aperture > 0.0 && forward > 0.0 {
+            target = ray.get_origin() + &(ray.get_direction() * (self.focal_distance / forward));
+            let (disk_x, disk_y) = concentric_disk(lens_u, lens_v);
+            origin = &origin + &Vector::new(disk_x * self.aperture, disk_y * self.aperture, 0.0);
+        }
+        let origin = &self.inverted * &origin;
+        let target = &self.inverted * &target;
+        let direction = (&target - &origin).get_normal();
+        Some(Ray::new(origin, direction))
+    }
+}
 
-        let pixel = &self.inverted * &Point::new(world_x, world_y, -1.0);
-        let origin = &self.inverted * &Point::new(0.0, 0.0, 0.0);
-        let direction = (&pixel - &origin).get_normal();
-        Ray::new(origin, direction)
+/// Shirley and Chiu's area-preserving map from the unit square to the unit
+/// disk.
+fn concentric_disk(u: f64, v: f64) -> (f64, f64) {
+    let a = 2.0 * u - 1.0;
+    let b = 2.0 * v - 1.0;
+    if a == 0.0 && b == 0.0 {
+        return (0.0, 0.0);
     }
+    let (radius, angle) = if a.abs() > b.abs() {
+        (a, FRAC_PI_4 * (b / a))
+    } else {
+        (b, FRAC_PI_2 - FRAC_PI_4 * (a / b))
+    };
+    (radius * angle.cos(), radius * angle.sin())
 }
+
+#[cfg(test)]
+#[path = "tests/camera_tests.rs"]
+mod tests;
@@ -0,0 +1,38 @@
+use std::f64::consts::PI;
+
+use crate::primitives::{Point, Ray, Vector};
+
+use super::Projection;
+
+/// Full 360 by 180 degree panorama: longitude runs across the image with the
+/// view direction in the middle, latitude runs from straight up at the top
+/// to straight down at the bottom.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EquirectangularProjection;
+
+impl EquirectangularProjection {
+    pub fn new() -> Self {
+        EquirectangularProjection
+    }
+}
+
+impl Projection for EquirectangularProjection {
+    fn get_camera_ray(&self, u: f64, v: f64, _aspect: f64) -> Option<Ray> {
+        let longitude = (u - 0.5) * 2.0 * PI;
+        let latitude = (0.5 - v) * PI;
+        let (sin_longitude, cos_longitude) = longitude.sin_cos();
+        let (sin_latitude, cos_latitude) = latitude.sin_cos();
+        Some(Ray::new(
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(
+                -cos_latitude * sin_longitude,
+                sin_latitude,
+                -cos_latitude * cos_longitude,
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/equirectangular_projection_tests.rs"]
+mod tests;
@@ -0,0 +1,60 @@
+use std::f64::consts::PI;
+
+use crate::primitives::{Point, Ray, Vector};
+
+use super::{get_screen_coordinates, Projection};
+
+/// Equidistant fisheye: the angle from the view direction grows linearly with
+/// the distance from the image centre, filling a circle that touches the
+/// longer image sides.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FisheyeProjection {
+    fov: f64,
+}
+
+impl FisheyeProjection {
+    pub fn new(fov_in_radians: f64) -> Self {
+        FisheyeProjection {
+            fov: fov_in_radians,
+        }
+    }
+
+    pub fn get_fov(&self) -> f64 {
+        self.fov
+    }
+}
+
+impl Default for FisheyeProjection {
+    fn default() -> Self {
+        FisheyeProjection::new(PI)
+    }
+}
+
+impl Projection for FisheyeProjection {
+    fn get_camera_ray(&self, u: f64, v: f64, aspect: f64) -> Option<Ray> {
+        let (screen_x, screen_y) = get_screen_coordinates(u, v, aspect);
+        let radius = (screen_x * screen_x + screen_y * screen_y).sqrt();
+        if radius > 1.0 {
+            return None;
+        }
+        let theta = radius * self.fov / 2.0;
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (direction_x, direction_y) = if radius == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (screen_x / radius, screen_y / radius)
+        };
+        Some(Ray::new(
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(
+                -direction_x * sin_theta,
+                direction_y * sin_theta,
+                -cos_theta,
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/fisheye_projection_tests.rs"]
+mod tests;
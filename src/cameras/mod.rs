@@ -1,3 +1,15 @@
 mod camera;
+mod equirectangular_projection;
+mod fisheye_projection;
+mod orthographic_projection;
+mod perspective_projection;
+mod projection;
+mod sampler;
 
-pub use camera::Camera;
\ No newline at end of file
+pub use camera::Camera;
+pub use equirectangular_projection::EquirectangularProjection;
+pub use fisheye_projection::FisheyeProjection;
+pub use orthographic_projection::OrthographicProjection;
+pub use perspective_projection::PerspectiveProjection;
+pub use projection::{get_screen_coordinates, Projection};
+pub use sampler::{SamplePattern, Sampler};
\ No newline at end of file
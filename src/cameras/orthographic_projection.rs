@@ -0,0 +1,35 @@
+use crate::primitives::{Point, Ray, Vector};
+
+use super::{get_screen_coordinates, Projection};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrthographicProjection {
+    view_width: f64,
+}
+
+impl OrthographicProjection {
+    /// Creates a parallel projection showing `view_width` world units across
+    /// the longer image side.
+    pub fn new(view_width: f64) -> Self {
+        OrthographicProjection { view_width }
+    }
+
+    pub fn get_view_width(&self) -> f64 {
+        self.view_width
+    }
+}
+
+impl Projection for OrthographicProjection {
+    fn get_camera_ray(&self, u: f64, v: f64, aspect: f64) -> Option<Ray> {
+        let (screen_x, screen_y) = get_screen_coordinates(u, v, aspect);
+        let half_view = self.view_width / 2.0;
+        Some(Ray::new(
+            Point::new(-screen_x * half_view, screen_y * half_view, 0.0),
+            Vector::new(0.0, 0.0, -1.0),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/orthographic_projection_tests.rs"]
+mod tests;
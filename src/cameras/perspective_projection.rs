@@ -0,0 +1,38 @@
+use crate::primitives::{Point, Ray, Vector};
+
+use super::{get_screen_coordinates, Projection};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PerspectiveProjection {
+    fov: f64,
+    half_view: f64,
+}
+
+impl PerspectiveProjection {
+    /// Creates a pinhole projection whose field of view spans the longer
+    /// image side.
+    pub fn new(fov_in_radians: f64) -> Self {
+        PerspectiveProjection {
+            fov: fov_in_radians,
+            half_view: (fov_in_radians / 2.0).tan(),
+        }
+    }
+
+    pub fn get_fov(&self) -> f64 {
+        self.fov
+    }
+}
+
+impl Projection for PerspectiveProjection {
+    fn get_camera_ray(&self, u: f64, v: f64, aspect: f64) -> Option<Ray> {
+        let (screen_x, screen_y) = get_screen_coordinates(u, v, aspect);
+        Some(Ray::new(
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(-screen_x * self.half_view, screen_y * self.half_view, -1.0),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/perspective_projection_tests.rs"]
+mod tests;
@@ -0,0 +1,27 @@
+use std::fmt::Debug;
+
+use crate::primitives::Ray;
+
+/// Maps a position on the image to a ray in camera space, where the camera
+/// sits at the origin looking down -z with +y up and +x to the left.
+pub trait Projection: Debug + Send + Sync {
+    /// `u` and `v` run from 0 to 1 from the top-left corner of the image and
+    /// `aspect` is width over height. Returns `None` for image positions the
+    /// projection does not cover.
+    fn get_camera_ray(&self, u: f64, v: f64, aspect: f64) -> Option<Ray>;
+}
+
+/// Converts image coordinates to screen coordinates in which the longer image
+/// side spans `[-1, 1]`, with x growing to the right and y growing upwards.
+pub fn get_screen_coordinates(u: f64, v: f64, aspect: f64) -> (f64, f64) {
+    let (half_width, half_height) = if aspect >= 1.0 {
+        (1.0, 1.0 / aspect)
+    } else {
+        (aspect, 1.0)
+    };
+    ((2.0 * u - 1.0) * half_width, (1.0 - 2.0 * v) * half_height)
+}
+
+#[cfg(test)]
+#[path = "tests/projection_tests.rs"]
+mod tests;
@@ -0,0 +1,194 @@
+const LENS_STREAM: u64 = 0x5851_f42d_4c95_7f2d;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SamplePattern {
+    Grid,
+    Jittered,
+    Halton,
+    Sobol,
+}
+
+/// Chooses where inside each pixel camera rays are shot. Offsets are in
+/// `[0, 1)` and depend only on the seed and the pixel, so serial and parallel
+/// renders agree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sampler {
+    pattern: SamplePattern,
+    samples: usize,
+    seed: u64,
+}
+
+impl Sampler {
+    pub fn new(pattern: SamplePattern, samples: usize) -> Self {
+        Sampler {
+            pattern,
+            samples: samples.max(1),
+            seed: 0,
+        }
+    }
+
+    pub fn get_pattern(&self) -> SamplePattern {
+        self.pattern
+    }
+
+    pub fn set_pattern(&mut self, pattern: SamplePattern) {
+        self.pattern = pattern;
+    }
+
+    pub fn get_samples(&self) -> usize {
+        self.samples
+    }
+
+    pub fn set_samples(&mut self, samples: usize) {
+        self.samples = samples.max(1);
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    pub fn get_offsets(&self, x: usize, y: usize) -> Vec<(f64, f64)> {
+        let mut random = Random::for_pixel(self.seed, x, y);
+        match self.pattern {
+            SamplePattern::Grid => self.stratified(|| (0.5, 0.5)),
+            SamplePattern::Jittered => self.stratified(|| (random.next_f64(), random.next_f64())),
+            SamplePattern::Halton => {
+                let shift = (random.next_f64(), random.next_f64());
+                (0..self.samples)
+                    .map(|index| {
+                        let point = (radical_inverse(index + 1, 2), radical_inverse(index + 1, 3));
+                        rotate(point, shift)
+                    })
+                    .collect()
+            }
+            SamplePattern::Sobol => {
+                let scramble = (random.next_u64() as u32, (random.next_u64() >> 32) as u32);
+                (0..self.samples as u32)
+                    .map(|index| {
+                        (
+                            to_unit(index.reverse_bits() ^ scramble.0),
+                            to_unit(sobol_second_dimension(index) ^ scramble.1),
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Offsets for sampling the lens aperture, drawn from an independent
+    /// stream and shuffled so they are not correlated with `get_offsets`.
+    pub fn get_lens_offsets(&self, x: usize, y: usize) -> Vec<(f64, f64)> {
+        let lens = Sampler {
+            seed: self.seed ^ LENS_STREAM,
+            ..self.clone()
+        };
+        let mut offsets = lens.get_offsets(x, y);
+        let mut random = Random::for_pixel(!lens.seed, x, y);
+        for index in (1..offsets.len()).rev() {
+            let other = (random.next_u64() % (index as u64 + 1)) as usize;
+            offsets.swap(index, other);
+        }
+        offsets
+    }
+
+    /// Splits the pixel into one cell of equal area per sample. When the count
+    /// is not a perfect square some rows get an extra column and are made
+    /// taller to match, so every row is filled and the cells stay centred.
+    fn stratified<F: FnMut() -> (f64, f64)>(&self, mut position_in_cell: F) -> Vec<(f64, f64)> {
+        let total = self.samples as f64;
+        let rows = (total.sqrt() as usize).max(1);
+        let mut offsets = Vec::with_capacity(self.samples);
+        let mut before = 0;
+        for row in 0..rows {
+            let columns = self.samples / rows + usize::from(row < self.samples % rows);
+            for column in 0..columns {
+                let (cell_x, cell_y) = position_in_cell();
+                offsets.push((
+                    (column as f64 + cell_x) / columns as f64,
+                    (before as f64 + cell_y * columns as f64) / total,
+                ));
+            }
+            before += columns;
+        }
+        offsets
+    }
+}
+
+impl Default for Sampler {
+    fn default() -> Self {
+        Sampler::new(SamplePattern::Grid, 1)
+    }
+}
+
+fn radical_inverse(mut index: usize, base: usize) -> f64 {
+    let mut result = 0.0;
+    let mut fraction = 1.0 / base as f64;
+    while index > 0 {
+        result += (index % base) as f64 * fraction;
+        index /= base;
+        fraction /= base as f64;
+    }
+    result
+}
+
+fn rotate(point: (f64, f64), shift: (f64, f64)) -> (f64, f64) {
+    ((point.0 + shift.0).fract(), (point.1 + shift.1).fract())
+}
+
+fn sobol_second_dimension(index: u32) -> u32 {
+    let mut direction = 1u32 << 31;
+    let mut result = 0;
+    let mut index = index;
+    while index > 0 {
+        if index & 1 == 1 {
+            result ^= direction;
+        }
+        index >>= 1;
+        direction ^= direction >> 1;
+    }
+    result
+}
+
+fn to_unit(value: u32) -> f64 {
+    value as f64 / (1u64 << 32) as f64
+}
+
+/// SplitMix64, seeded from the sampler seed and the pixel coordinates.
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    fn for_pixel(seed: u64, x: usize, y: usize) -> Self {
+        let mut random = Random::new(
+            seed ^ (x as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)
+                ^ (y as u64).wrapping_mul(0xc2b2_ae3d_27d4_eb4f),
+        );
+        random.next_u64();
+        random
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/sampler_tests.rs"]
+mod tests;
@@ -0,0 +1,326 @@
+use std::f64::consts::PI;
+
+use crate::primitives::{Point, Vector};
+
+use super::{concentric_disk, Camera};
+use crate::cameras::{EquirectangularProjection, FisheyeProjection, OrthographicProjection};
+
+const EPSILON: f64 = 1e-9;
+
+fn camera() -> Camera {
+    Camera::new(11, 11, PI / 2.)
+}
+
+fn point_at_z(origin: &Point, direction: &Vector, z: f64) -> Point {
+    let t = (z - origin.get_z()) / direction.get_z();
+    Point::new(
+        origin.get_x() + direction.get_x() * t,
+        origin.get_y() + direction.get_y() * t,
+        z,
+    )
+}
+
+#[test]
+fn concentric_disk_stays_inside_unit_disk() {
+    for u in 0..10 {
+        for v in 0..10 {
+            let (x, y) = concentric_disk(u as f64 / 10., v as f64 / 10.);
+            assert!(x * x + y * y <= 1. + EPSILON);
+        }
+    }
+    assert_eq!((0., 0.), concentric_disk(0.5, 0.5));
+    let (x, y) = concentric_disk(1., 0.5);
+    assert!((1. - x).abs() < EPSILON && y.abs() < EPSILON);
+}
+
+#[test]
+fn pinhole_lens_ray_ignores_lens_sample() {
+    let camera = camera();
+
+    for (lens_u, lens_v) in [(0., 0.), (0.3, 0.9), (0.99, 0.5)] {
+        assert_eq!(
+            camera.get_sample_ray(3, 8, 0.25, 0.75).unwrap(),
+            camera
+                .get_lens_ray(3, 8, 0.25, 0.75, lens_u, lens_v)
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn lens_rays_start_inside_aperture() {
+    let mut camera = camera();
+    camera.set_aperture(0.25);
+    camera.set_focal_distance(4.);
+
+    for (lens_u, lens_v) in [(0., 0.), (0.1, 0.8), (0.99, 0.99), (0.5, 0.)] {
+        let ray = camera.get_lens_ray(2, 5, 0.5, 0.5, lens_u, lens_v).unwrap();
+        let origin = ray.get_origin();
+        let radius = (origin.get_x().powi(2) + origin.get_y().powi(2)).sqrt();
+        assert!(radius <= 0.25 + EPSILON);
+        assert!(origin.get_z().abs() < EPSILON);
+    }
+}
+
+#[test]
+fn lens_rays_converge_on_focal_plane() {
+    let mut camera = camera();
+    camera.set_aperture(0.5);
+    camera.set_focal_distance(6.);
+    let pinhole = camera.get_sample_ray(7, 2, 0.3, 0.6).unwrap();
+    let expected = point_at_z(pinhole.get_origin(), pinhole.get_direction(), -6.);
+
+    for (lens_u, lens_v) in [(0., 0.), (0.2, 0.7), (0.9, 0.1)] {
+        let ray = camera.get_lens_ray(7, 2, 0.3, 0.6, lens_u, lens_v).unwrap();
+        let focus = point_at_z(ray.get_origin(), ray.get_direction(), -6.);
+        assert!((expected.get_x() - focus.get_x()).abs() < EPSILON);
+        assert!((expected.get_y() - focus.get_y()).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn lens_rays_follow_camera_position() {
+    let mut camera = camera();
+    camera.set_aperture(0.1);
+    camera.set_focal_distance(2.);
+    camera.set_position(
+        &Point::new(0., 0., 10.),
+        &Point::new(0., 0., 0.),
+        &Vector::new(0., 1., 0.),
+    );
+
+    let ray = camera.get_lens_ray(5, 5, 0.5, 0.5, 0.8, 0.8).unwrap();
+
+    assert!((10. - ray.get_origin().get_z()).abs() < EPSILON);
+    let focus = point_at_z(ray.get_origin(), ray.get_direction(), 8.);
+    assert!(focus.get_x().abs() < EPSILON && focus.get_y().abs() < EPSILON);
+}
+
+#[test]
+fn negative_aperture_is_treated_as_pinhole() {
+    let mut camera = camera();
+
+    camera.set_aperture(-1.);
+
+    assert_eq!(0., camera.get_aperture());
+}
+
+#[test]
+fn non_positive_focal_distance_is_ignored() {
+    let mut camera = camera();
+    camera.set_focal_distance(3.);
+
+    camera.set_focal_distance(0.);
+    camera.set_focal_distance(-2.);
+    camera.set_focal_distance(f64::NAN);
+
+    assert_eq!(3., camera.get_focal_distance());
+}
+
+#[test]
+fn orthographic_camera_honours_position() {
+    let mut camera = Camera::with_projection(10, 10, OrthographicProjection::new(4.));
+    camera.set_position(
+        &Point::new(10., 0., 0.),
+        &Point::new(0., 0., 0.),
+        &Vector::new(0., 1., 0.),
+    );
+
+    let left = camera.get_sample_ray(0, 5, 0., 0.).unwrap();
+
+    assert!((10. - left.get_origin().get_x()).abs() < EPSILON);
+    assert!((-2. - left.get_origin().get_z()).abs() < EPSILON);
+    assert!((-1. - left.get_direction().get_x()).abs() < EPSILON);
+}
+
+#[test]
+fn fisheye_camera_has_no_rays_in_corners() {
+    let camera = Camera::with_projection(9, 9, FisheyeProjection::default());
+
+    assert!(camera.get_ray(0, 0).is_none());
+    assert!(camera.get_ray(4, 4).is_some());
+}
+
+#[test]
+fn set_projection_replaces_perspective() {
+    let mut camera = camera();
+
+    camera.set_projection(EquirectangularProjection::new());
+    let behind = camera.get_sample_ray(0, 5, 0., 0.5).unwrap();
+
+    assert!((1. - behind.get_direction().get_z()).abs() < EPSILON);
+}
+
+fn assert_direction(expected: (f64, f64, f64), direction: &Vector) {
+    let expected = Vector::new(expected.0, expected.1, expected.2).get_normal();
+    assert!((expected.get_x() - direction.get_x()).abs() < 1e-5);
+    assert!((expected.get_y() - direction.get_y()).abs() < 1e-5);
+    assert!((expected.get_z() - direction.get_z()).abs() < 1e-5);
+}
+
+#[test]
+fn camera_does_not_need_a_canvas() {
+    let camera = Camera::new(160, 120, PI / 2.);
+
+    assert_eq!(160, camera.get_width());
+    assert_eq!(120, camera.get_height());
+    assert!((4. / 3. - camera.get_aspect()).abs() < EPSILON);
+}
+
+#[test]
+fn ray_through_center_of_canvas() {
+    let camera = Camera::new(201, 101, PI / 2.);
+
+    let ray = camera.get_ray(100, 50).unwrap();
+
+    assert!(ray.get_origin().get_x().abs() < EPSILON);
+    assert_direction((0., 0., -1.), ray.get_direction());
+}
+
+#[test]
+fn ray_through_corner_of_canvas() {
+    let camera = Camera::new(201, 101, PI / 2.);
+
+    let ray = camera.get_ray(0, 0).unwrap();
+
+    assert_direction((0.66519, 0.33259, -0.66851), ray.get_direction());
+}
+
+#[test]
+fn rays_at_image_corners_span_field_of_view() {
+    let camera = Camera::new(4, 2, PI / 2.);
+
+    for (x, y, expected) in [
+        (0., 0., (1., 0.5, -1.)),
+        (4., 0., (-1., 0.5, -1.)),
+        (0., 2., (1., -0.5, -1.)),
+        (4., 2., (-1., -0.5, -1.)),
+    ] {
+        let ray = camera.get_ray_at(x, y).unwrap();
+        assert_direction(expected, ray.get_direction());
+    }
+}
+
+#[test]
+fn portrait_field_of_view_spans_height() {
+    let camera = Camera::new(2, 4, PI / 2.);
+
+    assert_direction(
+        (0., 1., -1.),
+        camera.get_ray_at(1., 0.).unwrap().get_direction(),
+    );
+    assert_direction(
+        (0.5, 0., -1.),
+        camera.get_ray_at(0., 2.).unwrap().get_direction(),
+    );
+}
+
+#[test]
+fn get_ray_at_matches_pixel_samples() {
+    let camera = camera();
+
+    assert_eq!(
+        camera.get_sample_ray(3, 7, 0.25, 0.75).unwrap(),
+        camera.get_ray_at(3.25, 7.75).unwrap()
+    );
+    assert_eq!(
+        camera.get_ray(3, 7).unwrap(),
+        camera.get_ray_at(3.5, 7.5).unwrap()
+    );
+}
+
+#[test]
+fn pixels_outside_canvas_are_clamped_to_last_pixel() {
+    let camera = Camera::new(8, 4, PI / 2.);
+
+    let last = camera.get_ray(7, 3).unwrap();
+
+    assert_eq!(last, camera.get_ray(8, 4).unwrap());
+    assert_eq!(last, camera.get_ray(100, 100).unwrap());
+    assert_eq!(
+        camera.get_ray_at(8., 4.).unwrap(),
+        camera.get_ray_at(20., 9.).unwrap()
+    );
+}
+
+#[test]
+fn resize_keeps_position_and_field_of_view() {
+    let mut camera = Camera::new(11, 11, PI / 2.);
+    camera.set_position(
+        &Point::new(0., 2., -5.),
+        &Point::new(0., 0., 0.),
+        &Vector::new(0., 1., 0.),
+    );
+    let center = camera.get_ray(5, 5).unwrap();
+
+    camera.resize(21, 11);
+
+    assert_eq!(21, camera.get_width());
+    assert_eq!(11, camera.get_height());
+    assert_eq!(center, camera.get_ray(10, 5).unwrap());
+
+    let mut unpositioned = Camera::new(11, 11, PI / 2.);
+    unpositioned.resize(21, 11);
+    let left = unpositioned.get_ray_at(0., 5.5).unwrap();
+    assert_direction((1., 0., -1.), left.get_direction());
+}
+
+#[test]
+fn empty_resolution_keeps_one_pixel() {
+    let mut camera = Camera::new(0, 0, PI / 2.);
+    assert_eq!((1, 1), (camera.get_width(), camera.get_height()));
+    assert!(camera.get_ray(3, 3).is_some());
+
+    camera.resize(5, 0);
+
+    assert_eq!((5, 1), (camera.get_width(), camera.get_height()));
+}
+
+#[test]
+fn fixed_aspect_stretches_pixels() {
+    let mut camera = Camera::new(4, 4, PI / 2.);
+
+    camera.set_aspect(Some(2.));
+
+    assert_eq!(2., camera.get_aspect());
+    assert_direction(
+        (1., 0., -1.),
+        camera.get_ray_at(0., 2.).unwrap().get_direction(),
+    );
+    assert_direction(
+        (0., 0.5, -1.),
+        camera.get_ray_at(2., 0.).unwrap().get_direction(),
+    );
+    assert_direction(
+        (-1., -0.5, -1.),
+        camera.get_ray_at(4., 4.).unwrap().get_direction(),
+    );
+}
+
+#[test]
+fn resize_keeps_fixed_aspect() {
+    let mut camera = Camera::new(4, 4, PI / 2.);
+    camera.set_aspect(Some(2.));
+    let corner = camera.get_ray_at(0., 0.).unwrap();
+
+    camera.resize(3, 5);
+
+    assert_eq!(2., camera.get_aspect());
+    assert_eq!(corner, camera.get_ray_at(0., 0.).unwrap());
+
+    camera.set_aspect(None);
+
+    assert!((0.6 - camera.get_aspect()).abs() < EPSILON);
+}
+
+#[test]
+fn invalid_aspect_is_ignored() {
+    let mut camera = Camera::new(4, 2, PI / 2.);
+    camera.set_aspect(Some(1.5));
+
+    camera.set_aspect(Some(0.));
+    camera.set_aspect(Some(-1.));
+    camera.set_aspect(Some(f64::INFINITY));
+
+    assert_eq!(1.5, camera.get_aspect());
+}
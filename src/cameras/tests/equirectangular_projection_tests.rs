@@ -0,0 +1,22 @@
+use crate::{cameras::Projection, primitives::Vector};
+
+use super::EquirectangularProjection;
+
+#[test]
+fn covers_the_full_sphere_of_directions() {
+    let projection = EquirectangularProjection::new();
+    let cases = [
+        ((0.5, 0.5), Vector::new(0., 0., -1.)),
+        ((0.75, 0.5), Vector::new(-1., 0., 0.)),
+        ((0.25, 0.5), Vector::new(1., 0., 0.)),
+        ((0., 0.5), Vector::new(0., 0., 1.)),
+        ((1., 0.5), Vector::new(0., 0., 1.)),
+        ((0.5, 0.), Vector::new(0., 1., 0.)),
+        ((0.3, 1.), Vector::new(0., -1., 0.)),
+    ];
+
+    for ((u, v), expected) in cases {
+        let ray = projection.get_camera_ray(u, v, 2.).unwrap();
+        assert_eq!(&expected, ray.get_direction());
+    }
+}
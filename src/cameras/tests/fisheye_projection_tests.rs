@@ -0,0 +1,40 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use crate::{cameras::Projection, primitives::Vector};
+
+use super::FisheyeProjection;
+
+#[test]
+fn default_covers_a_hemisphere() {
+    let projection = FisheyeProjection::default();
+
+    let centre = projection.get_camera_ray(0.5, 0.5, 1.).unwrap();
+    let right = projection.get_camera_ray(1., 0.5, 1.).unwrap();
+    let top = projection.get_camera_ray(0.5, 0., 1.).unwrap();
+
+    assert_eq!(PI, projection.get_fov());
+    assert_eq!(&Vector::new(0., 0., -1.), centre.get_direction());
+    assert_eq!(&Vector::new(-1., 0., 0.), right.get_direction());
+    assert_eq!(&Vector::new(0., 1., 0.), top.get_direction());
+}
+
+#[test]
+fn angle_grows_linearly_with_radius() {
+    let projection = FisheyeProjection::default();
+
+    let ray = projection.get_camera_ray(0.25, 0.5, 1.).unwrap();
+
+    assert_eq!(
+        &Vector::new(FRAC_1_SQRT_2, 0., -FRAC_1_SQRT_2),
+        ray.get_direction(),
+    );
+}
+
+#[test]
+fn corners_outside_image_circle_have_no_ray() {
+    let projection = FisheyeProjection::new(PI / 2.);
+
+    assert!(projection.get_camera_ray(0., 0., 1.).is_none());
+    assert!(projection.get_camera_ray(0.99, 0.99, 1.).is_none());
+    assert!(projection.get_camera_ray(0.5, 0.05, 2.).is_some());
+}
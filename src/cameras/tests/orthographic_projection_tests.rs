@@ -0,0 +1,23 @@
+use crate::{
+    cameras::Projection,
+    primitives::{Point, Vector},
+};
+
+use super::OrthographicProjection;
+
+#[test]
+fn rays_are_parallel_and_spread_over_view_width() {
+    let projection = OrthographicProjection::new(4.);
+
+    let centre = projection.get_camera_ray(0.5, 0.5, 1.).unwrap();
+    let left = projection.get_camera_ray(0., 0.5, 1.).unwrap();
+    let bottom_right = projection.get_camera_ray(1., 1., 2.).unwrap();
+
+    assert_eq!(&Point::new(0., 0., 0.), centre.get_origin());
+    assert_eq!(&Point::new(2., 0., 0.), left.get_origin());
+    assert_eq!(&Point::new(-2., -1., 0.), bottom_right.get_origin());
+    for ray in [centre, left, bottom_right] {
+        assert_eq!(&Vector::new(0., 0., -1.), ray.get_direction());
+    }
+    assert_eq!(4., projection.get_view_width());
+}
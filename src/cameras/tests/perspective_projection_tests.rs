@@ -0,0 +1,32 @@
+use std::f64::consts::PI;
+
+use crate::{
+    cameras::Projection,
+    primitives::{Point, Vector},
+};
+
+use super::PerspectiveProjection;
+
+#[test]
+fn centre_ray_looks_down_negative_z() {
+    let projection = PerspectiveProjection::new(PI / 2.);
+
+    let ray = projection.get_camera_ray(0.5, 0.5, 2.).unwrap();
+
+    assert_eq!(&Point::new(0., 0., 0.), ray.get_origin());
+    assert_eq!(&Vector::new(0., 0., -1.), ray.get_direction());
+}
+
+#[test]
+fn fov_spans_longer_side() {
+    let projection = PerspectiveProjection::new(PI / 2.);
+
+    let left = projection.get_camera_ray(0., 0.5, 2.).unwrap();
+    let top = projection.get_camera_ray(0.5, 0., 2.).unwrap();
+    let tall_top = projection.get_camera_ray(0.5, 0., 0.5).unwrap();
+
+    assert_eq!(&Vector::new(1., 0., -1.), left.get_direction());
+    assert_eq!(&Vector::new(0., 0.5, -1.), top.get_direction());
+    assert_eq!(&Vector::new(0., 1., -1.), tall_top.get_direction());
+    assert_eq!(PI / 2., projection.get_fov());
+}
@@ -0,0 +1,10 @@
+use super::get_screen_coordinates;
+
+#[test]
+fn get_screen_coordinates_spans_longer_side() {
+    assert_eq!((-1., 1.), get_screen_coordinates(0., 0., 1.));
+    assert_eq!((1., -1.), get_screen_coordinates(1., 1., 1.));
+    assert_eq!((0., 0.), get_screen_coordinates(0.5, 0.5, 2.));
+    assert_eq!((1., 0.5), get_screen_coordinates(1., 0., 2.));
+    assert_eq!((-0.5, -1.), get_screen_coordinates(0., 1., 0.5));
+}
@@ -0,0 +1,163 @@
+use super::{SamplePattern, Sampler};
+
+const PATTERNS: [SamplePattern; 4] = [
+    SamplePattern::Grid,
+    SamplePattern::Jittered,
+    SamplePattern::Halton,
+    SamplePattern::Sobol,
+];
+
+fn with_seed(pattern: SamplePattern, samples: usize, seed: u64) -> Sampler {
+    let mut sampler = Sampler::new(pattern, samples);
+    sampler.set_seed(seed);
+    sampler
+}
+
+fn count_per_cell(offsets: &[(f64, f64)], columns: usize, rows: usize) -> Vec<usize> {
+    let mut counts = vec![0; columns * rows];
+    for (x, y) in offsets {
+        let column = (x * columns as f64) as usize;
+        let row = (y * rows as f64) as usize;
+        counts[row * columns + column] += 1;
+    }
+    counts
+}
+
+#[test]
+fn default_sampler_shoots_through_pixel_centre() {
+    let sampler = Sampler::default();
+
+    assert_eq!(1, sampler.get_samples());
+    assert_eq!(vec![(0.5, 0.5)], sampler.get_offsets(3, 7));
+}
+
+#[test]
+fn new_requires_at_least_one_sample() {
+    let mut sampler = Sampler::new(SamplePattern::Jittered, 0);
+    assert_eq!(1, sampler.get_samples());
+
+    sampler.set_samples(0);
+    assert_eq!(1, sampler.get_offsets(0, 0).len());
+}
+
+#[test]
+fn grid_places_samples_at_cell_centres() {
+    let sampler = Sampler::new(SamplePattern::Grid, 4);
+
+    assert_eq!(
+        vec![(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)],
+        sampler.get_offsets(0, 0)
+    );
+}
+
+#[test]
+fn grid_and_jittered_are_centred_for_any_sample_count() {
+    for samples in [3, 5, 8] {
+        let grid = Sampler::new(SamplePattern::Grid, samples).get_offsets(0, 0);
+        let (sum_x, sum_y) = grid
+            .iter()
+            .fold((0., 0.), |(x, y), offset| (x + offset.0, y + offset.1));
+
+        assert!((0.5 - sum_x / samples as f64).abs() < 1e-9, "{samples}");
+        assert!((0.5 - sum_y / samples as f64).abs() < 1e-9, "{samples}");
+    }
+}
+
+#[test]
+fn jittered_fills_every_row_for_uneven_counts() {
+    let offsets = with_seed(SamplePattern::Jittered, 5, 3).get_offsets(4, 4);
+    let (top, bottom) = offsets.split_at(3);
+
+    assert!(top.iter().all(|(_, y)| *y < 0.6));
+    assert!(bottom.iter().all(|(_, y)| *y >= 0.6));
+    assert_eq!(vec![1, 1, 1], count_per_cell(top, 3, 1));
+    assert_eq!(vec![1, 1], count_per_cell(bottom, 2, 1));
+}
+
+#[test]
+fn all_patterns_stay_inside_the_pixel() {
+    for pattern in PATTERNS {
+        for samples in [1, 2, 5, 16, 33] {
+            let offsets = with_seed(pattern, samples, 9).get_offsets(12, 34);
+
+            assert_eq!(samples, offsets.len());
+            for (x, y) in offsets {
+                assert!((0. ..1.).contains(&x) && (0. ..1.).contains(&y));
+            }
+        }
+    }
+}
+
+#[test]
+fn jittered_and_sobol_put_one_sample_in_each_stratum() {
+    for pattern in [SamplePattern::Jittered, SamplePattern::Sobol] {
+        let offsets = with_seed(pattern, 16, 5).get_offsets(2, 3);
+
+        assert!(count_per_cell(&offsets, 4, 4)
+            .iter()
+            .all(|count| *count == 1));
+    }
+}
+
+#[test]
+fn sobol_is_stratified_in_each_dimension() {
+    let offsets = with_seed(SamplePattern::Sobol, 16, 11).get_offsets(8, 1);
+
+    assert!(count_per_cell(&offsets, 16, 1)
+        .iter()
+        .all(|count| *count == 1));
+    assert!(count_per_cell(&offsets, 1, 16)
+        .iter()
+        .all(|count| *count == 1));
+    assert!(count_per_cell(&offsets, 2, 8)
+        .iter()
+        .all(|count| *count == 1));
+}
+
+#[test]
+fn halton_fills_strata_roughly_evenly() {
+    let offsets = with_seed(SamplePattern::Halton, 64, 3).get_offsets(4, 4);
+
+    let counts = count_per_cell(&offsets, 4, 4);
+    assert!(
+        counts.iter().all(|count| (2..=6).contains(count)),
+        "{counts:?}"
+    );
+}
+
+#[test]
+fn offsets_are_reproducible_for_a_seed() {
+    for pattern in [
+        SamplePattern::Jittered,
+        SamplePattern::Halton,
+        SamplePattern::Sobol,
+    ] {
+        let first = with_seed(pattern, 8, 42);
+        let second = with_seed(pattern, 8, 42);
+
+        assert_eq!(first.get_offsets(10, 20), second.get_offsets(10, 20));
+        assert_ne!(first.get_offsets(10, 20), first.get_offsets(11, 20));
+        assert_ne!(
+            first.get_offsets(10, 20),
+            with_seed(pattern, 8, 43).get_offsets(10, 20)
+        );
+    }
+}
+
+#[test]
+fn lens_offsets_are_stratified_but_independent_of_pixel_offsets() {
+    let sampler = with_seed(SamplePattern::Jittered, 16, 8);
+
+    let pixel = sampler.get_offsets(1, 2);
+    let lens = sampler.get_lens_offsets(1, 2);
+
+    assert_eq!(lens, sampler.get_lens_offsets(1, 2));
+    assert_ne!(pixel, lens);
+    assert!(count_per_cell(&lens, 4, 4).iter().all(|count| *count == 1));
+    let same_stratum = pixel
+        .iter()
+        .zip(lens.iter())
+        .filter(|(a, b)| (a.0 * 4.) as usize == (b.0 * 4.) as usize)
+        .count();
+    assert!(same_stratum < 16);
+}
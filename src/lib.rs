@@ -5,3 +5,5 @@ pub mod shading;
 pub mod cameras;
 pub mod tracing;
 pub mod scenes;
+pub mod loaders;
+pub mod tone_mapping;
\ No newline at end of file
@@ -1,7 +1,4 @@
-use crate::{
-    objects::Hittable,
-    primitives::{HitRecord, Point, RgbColor},
-};
+use crate::primitives::{HitRecord, Point, RgbColor};
 
 pub struct PointLight {
     location: Point,
@@ -26,7 +23,8 @@ impl PointLight {
 
     pub fn shade(&self, record: &HitRecord) -> RgbColor {
         let material = record.get_object().get_material();
-        let effective_color = material.get_color() * &self.intensity;
+        let surface_color = material.get_color_at(record.get_object(), record.get_hit_point());
+        let effective_color = &surface_color * &self.intensity;
         let lightv = (&self.location - record.get_hit_point()).get_normal();
         let ambient = &effective_color * material.get_ambient();
         if record.get_is_in_shadow() {
@@ -1,4 +1,10 @@
-use crate::primitives::{Point, RgbColor};
+use std::f64::consts::PI;
+
+use crate::{
+    objects::{Hittable, Plane},
+    primitives::{Point, Ray, RgbColor, SquareMatrix, Vector},
+    shading::{Material, StripePattern},
+};
 
 use super::PointLight;
 
@@ -12,7 +18,7 @@ fn constructor_operates_correctly() {
     assert_eq!(intensity, light.intensity);
 }
 
-#[test] 
+#[test]
 fn get_location_returns_correct_value() {
     let location = Point::new(1., -2., 3.);
     let intensity = RgbColor::new(0.5, 0.6, 0.7);
@@ -27,4 +33,31 @@ fn get_intensity_returns_correct_value() {
     let intensity = RgbColor::new(0.5, 0.6, 0.7);
     let light = PointLight::new(location, intensity.clone());
     assert_eq!(&intensity, light.get_intensity());
-}
\ No newline at end of file
+}
+
+#[test]
+fn shade_uses_material_pattern() {
+    let mut material = Material::default();
+    material.set_pattern(StripePattern::new(
+        RgbColor::new(1., 1., 1.),
+        RgbColor::new(0., 0., 0.),
+    ));
+    material.set_ambient(1.);
+    material.set_diffuse(0.);
+    material.set_specular(0.);
+    let mut wall = Plane::with_transform(SquareMatrix::rotation_x(PI / 2.));
+    wall.set_material(material);
+    let light = PointLight::new(Point::new(0., 0., -10.), RgbColor::new(1., 1., 1.));
+    let cases = [
+        (0.9, RgbColor::new(1., 1., 1.)),
+        (1.1, RgbColor::new(0., 0., 0.)),
+    ];
+
+    for (x, expected) in cases {
+        let ray = Ray::new(Point::new(x, 0., -5.), Vector::new(0., 0., 1.));
+        let hits = wall.get_hits(&ray);
+        let record = hits[0].get_hit_record(&ray, &hits);
+
+        assert_eq!(expected, light.shade(&record));
+    }
+}
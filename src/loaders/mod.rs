@@ -0,0 +1,3 @@
+mod obj_file;
+
+pub use obj_file::{ObjError, ObjFile, ObjGroup};
\ No newline at end of file
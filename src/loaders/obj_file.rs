@@ -0,0 +1,285 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use crate::{
+    objects::{Group, Hittable, SmoothTriangle, Triangle},
+    primitives::{Point, Vector, World},
+};
+
+#[derive(Debug)]
+pub enum ObjError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::Io(error) => write!(f, "could not read obj file: {}", error),
+            ObjError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+        }
+    }
+}
+
+impl Error for ObjError {}
+
+impl From<io::Error> for ObjError {
+    fn from(error: io::Error) -> Self {
+        ObjError::Io(error)
+    }
+}
+
+#[derive(Debug)]
+pub struct ObjGroup {
+    name: String,
+    objects: Vec<Box<dyn Hittable>>,
+}
+
+impl ObjGroup {
+    fn new(name: &str) -> Self {
+        ObjGroup {
+            name: name.to_string(),
+            objects: Vec::new(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_objects(&self) -> &Vec<Box<dyn Hittable>> {
+        &self.objects
+    }
+
+    pub fn into_objects(self) -> Vec<Box<dyn Hittable>> {
+        self.objects
+    }
+
+    pub fn into_group(self) -> Group {
+        let mut group = Group::new();
+        for object in self.objects {
+            group.add_boxed_child(object);
+        }
+        group
+    }
+}
+
+#[derive(Debug)]
+pub struct ObjFile {
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    texture_coordinates: Vec<(f64, f64)>,
+    groups: Vec<ObjGroup>,
+    ignored_lines: usize,
+}
+
+struct FaceVertex {
+    vertex: usize,
+    normal: Option<usize>,
+}
+
+impl ObjFile {
+    pub fn load(filename: &str) -> Result<Self, ObjError> {
+        let contents = fs::read_to_string(filename)?;
+        ObjFile::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, ObjError> {
+        let mut obj = ObjFile {
+            vertices: Vec::new(),
+            normals: Vec::new(),
+            texture_coordinates: Vec::new(),
+            groups: vec![ObjGroup::new("")],
+            ignored_lines: 0,
+        };
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.split('#').next().unwrap_or("").trim();
+            let mut tokens = line.split_whitespace();
+            let keyword = match tokens.next() {
+                Some(keyword) => keyword,
+                None => continue,
+            };
+            let arguments: Vec<&str> = tokens.collect();
+            match keyword {
+                "v" => {
+                    let [x, y, z] = parse_floats::<3>(&arguments, line_number)?;
+                    obj.vertices.push(Point::new(x, y, z));
+                }
+                "vn" => {
+                    let [x, y, z] = parse_floats::<3>(&arguments, line_number)?;
+                    obj.normals.push(Vector::new(x, y, z));
+                }
+                "vt" => {
+                    let [u] = parse_floats::<1>(&arguments, line_number)?;
+                    let v = match arguments.get(1) {
+                        Some(_) => parse_floats::<2>(&arguments, line_number)?[1],
+                        None => 0.0,
+                    };
+                    obj.texture_coordinates.push((u, v));
+                }
+                "f" => obj.parse_face(&arguments, line_number)?,
+                "g" | "o" => {
+                    let name = arguments.join(" ");
+                    obj.groups.push(ObjGroup::new(&name));
+                }
+                _ => obj.ignored_lines += 1,
+            }
+        }
+        obj.groups
+            .retain(|group| !group.name.is_empty() || !group.objects.is_empty());
+        Ok(obj)
+    }
+
+    pub fn get_vertices(&self) -> &Vec<Point> {
+        &self.vertices
+    }
+
+    pub fn get_normals(&self) -> &Vec<Vector> {
+        &self.normals
+    }
+
+    pub fn get_texture_coordinates(&self) -> &Vec<(f64, f64)> {
+        &self.texture_coordinates
+    }
+
+    pub fn get_groups(&self) -> &Vec<ObjGroup> {
+        &self.groups
+    }
+
+    pub fn get_group(&self, name: &str) -> Option<&ObjGroup> {
+        self.groups.iter().find(|group| group.name == name)
+    }
+
+    pub fn get_ignored_lines(&self) -> usize {
+        self.ignored_lines
+    }
+
+    pub fn get_triangle_count(&self) -> usize {
+        self.groups.iter().map(|group| group.objects.len()).sum()
+    }
+
+    pub fn into_group(self) -> Group {
+        let mut result = Group::new();
+        for group in self.groups {
+            result.add_child(group.into_group());
+        }
+        result
+    }
+
+    pub fn add_to_world(self, world: &mut World) {
+        for group in self.groups {
+            for object in group.objects {
+                world.add_boxed_object(object);
+            }
+        }
+    }
+
+    fn parse_face(&mut self, arguments: &[&str], line: usize) -> Result<(), ObjError> {
+        if arguments.len() < 3 {
+            return Err(parse_error(line, "face needs at least three vertices"));
+        }
+        let mut face = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            face.push(self.parse_face_vertex(argument, line)?);
+        }
+        for index in 1..face.len() - 1 {
+            let triangle = self.build_triangle(&face[0], &face[index], &face[index + 1]);
+            self.groups.last_mut().unwrap().objects.push(triangle);
+        }
+        Ok(())
+    }
+
+    fn parse_face_vertex(&self, argument: &str, line: usize) -> Result<FaceVertex, ObjError> {
+        let mut parts = argument.split('/');
+        let vertex = parts.next().unwrap_or("");
+        let vertex = resolve_index(vertex, self.vertices.len(), "vertex", line)?;
+        if let Some(texture) = parts.next() {
+            if !texture.is_empty() {
+                resolve_index(
+                    texture,
+                    self.texture_coordinates.len(),
+                    "texture coordinate",
+                    line,
+                )?;
+            }
+        }
+        let normal = match parts.next() {
+            Some(normal) if !normal.is_empty() => {
+                Some(resolve_index(normal, self.normals.len(), "normal", line)?)
+            }
+            _ => None,
+        };
+        if parts.next().is_some() {
+            return Err(parse_error(
+                line,
+                &format!("malformed face vertex '{}'", argument),
+            ));
+        }
+        Ok(FaceVertex { vertex, normal })
+    }
+
+    fn build_triangle(&self, a: &FaceVertex, b: &FaceVertex, c: &FaceVertex) -> Box<dyn Hittable> {
+        let p1 = self.vertices[a.vertex].clone();
+        let p2 = self.vertices[b.vertex].clone();
+        let p3 = self.vertices[c.vertex].clone();
+        match (a.normal, b.normal, c.normal) {
+            (Some(n1), Some(n2), Some(n3)) => Box::new(SmoothTriangle::new(
+                p1,
+                p2,
+                p3,
+                self.normals[n1].clone(),
+                self.normals[n2].clone(),
+                self.normals[n3].clone(),
+            )),
+            _ => Box::new(Triangle::new(p1, p2, p3)),
+        }
+    }
+}
+
+fn parse_error(line: usize, message: &str) -> ObjError {
+    ObjError::Parse {
+        line,
+        message: message.to_string(),
+    }
+}
+
+fn parse_floats<const N: usize>(arguments: &[&str], line: usize) -> Result<[f64; N], ObjError> {
+    if arguments.len() < N {
+        return Err(parse_error(
+            line,
+            &format!("expected {} numbers, found {}", N, arguments.len()),
+        ));
+    }
+    let mut result = [0.0; N];
+    for (value, argument) in result.iter_mut().zip(arguments.iter()) {
+        *value = argument
+            .parse()
+            .map_err(|_| parse_error(line, &format!("invalid number '{}'", argument)))?;
+    }
+    Ok(result)
+}
+
+fn resolve_index(token: &str, count: usize, kind: &str, line: usize) -> Result<usize, ObjError> {
+    let index: i64 = token
+        .parse()
+        .map_err(|_| parse_error(line, &format!("invalid {} index '{}'", kind, token)))?;
+    let resolved = if index > 0 {
+        index - 1
+    } else {
+        count as i64 + index
+    };
+    if index == 0 || resolved < 0 || resolved >= count as i64 {
+        return Err(parse_error(
+            line,
+            &format!("{} index {} out of range", kind, index),
+        ));
+    }
+    Ok(resolved as usize)
+}
+
+#[cfg(test)]
+#[path = "tests/obj_file_tests.rs"]
+mod tests;
@@ -0,0 +1,180 @@
+use crate::{
+    objects::Hittable,
+    primitives::{Point, Ray, Vector, World},
+};
+
+use super::{ObjError, ObjFile};
+
+#[test]
+fn parse_counts_unsupported_statements() {
+    let contents = "There was a young lady named Bright\n\
+                    who traveled much faster than light.\n\
+                    mtllib scene.mtl\n\
+                    usemtl glass\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(4, obj.get_ignored_lines());
+    assert_eq!(0, obj.get_triangle_count());
+}
+
+#[test]
+fn parse_skips_blank_lines_and_comments() {
+    let contents = "# a comment\n\n   \nv 1 2 3 # trailing comment\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(0, obj.get_ignored_lines());
+    assert_eq!(&vec![Point::new(1., 2., 3.)], obj.get_vertices());
+}
+
+#[test]
+fn parse_reads_vertices_normals_and_texture_coordinates() {
+    let contents = "v -1 1 0\n\
+                    v -1.0000 0.5000 0.0000\n\
+                    v 1 0 0\n\
+                    vn 0 0 1\n\
+                    vn 0.707 0 -0.707\n\
+                    vt 0.25 0.75\n\
+                    vt 0.5\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(
+        &vec![
+            Point::new(-1., 1., 0.),
+            Point::new(-1., 0.5, 0.),
+            Point::new(1., 0., 0.),
+        ],
+        obj.get_vertices()
+    );
+    assert_eq!(
+        &vec![Vector::new(0., 0., 1.), Vector::new(0.707, 0., -0.707)],
+        obj.get_normals()
+    );
+    assert_eq!(
+        &vec![(0.25, 0.75), (0.5, 0.0)],
+        obj.get_texture_coordinates()
+    );
+}
+
+#[test]
+fn parse_builds_triangles_from_faces() {
+    let contents = "v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\nf 1 2 3\nf 1 3 4\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(1, obj.get_groups().len());
+    assert_eq!(2, obj.get_triangle_count());
+}
+
+#[test]
+fn parse_fan_triangulates_polygons() {
+    let contents = "v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\nv 0 2 0\nf 1 2 3 4 5\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(3, obj.get_triangle_count());
+}
+
+#[test]
+fn parse_supports_slash_and_negative_indices() {
+    let contents = "v 0 1 0\nv -1 0 0\nv 1 0 0\n\
+                    vt 0 0\n\
+                    vn -1 0 0\nvn 1 0 0\nvn 0 1 0\n\
+                    f 1//3 2//1 3//2\n\
+                    f 1/1/3 2/1/1 3/1/2\n\
+                    f -3/-1 -2/-1 -1/-1\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(3, obj.get_triangle_count());
+}
+
+#[test]
+fn parse_splits_named_groups() {
+    let contents = "v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\n\
+                    g FirstGroup\nf 1 2 3\n\
+                    o SecondGroup\nf 1 3 4\nf 1 2 4\n";
+
+    let obj = ObjFile::parse(contents).unwrap();
+
+    assert_eq!(2, obj.get_groups().len());
+    assert_eq!(1, obj.get_group("FirstGroup").unwrap().get_objects().len());
+    assert_eq!(2, obj.get_group("SecondGroup").unwrap().get_objects().len());
+    assert!(obj.get_group("").is_none());
+}
+
+#[test]
+fn parse_reports_line_of_bad_number() {
+    let contents = "v 1 2 3\n\nv 1 two 3\n";
+
+    let error = ObjFile::parse(contents).unwrap_err();
+
+    match error {
+        ObjError::Parse { line, .. } => assert_eq!(3, line),
+        _ => panic!("expected a parse error"),
+    }
+    assert!(error.to_string().starts_with("line 3:"));
+}
+
+#[test]
+fn parse_reports_missing_coordinates() {
+    let error = ObjFile::parse("vn 1 2\n").unwrap_err();
+
+    assert!(matches!(error, ObjError::Parse { line: 1, .. }));
+}
+
+#[test]
+fn parse_reports_out_of_range_indices() {
+    let contents = "v 0 1 0\nv -1 0 0\nv 1 0 0\nf 1 2 4\n";
+
+    let error = ObjFile::parse(contents).unwrap_err();
+
+    assert!(matches!(error, ObjError::Parse { line: 4, .. }));
+}
+
+#[test]
+fn parse_reports_zero_index_and_short_faces() {
+    let zero = ObjFile::parse("v 0 1 0\nv -1 0 0\nv 1 0 0\nf 0 1 2\n").unwrap_err();
+    let short = ObjFile::parse("v 0 1 0\nv -1 0 0\nf 1 2\n").unwrap_err();
+
+    assert!(matches!(zero, ObjError::Parse { line: 4, .. }));
+    assert!(matches!(short, ObjError::Parse { line: 3, .. }));
+}
+
+#[test]
+fn load_reports_missing_file() {
+    let error = ObjFile::load("this/file/does/not/exist.obj").unwrap_err();
+
+    assert!(matches!(error, ObjError::Io(_)));
+}
+
+#[test]
+fn add_to_world_makes_triangles_hittable() {
+    let contents = "v 0 1 0\nv -1 0 0\nv 1 0 0\nf 1 2 3\n";
+    let obj = ObjFile::parse(contents).unwrap();
+    let mut world = World::new();
+
+    obj.add_to_world(&mut world);
+    let ray = Ray::new(Point::new(0., 0.5, -2.), Vector::new(0., 0., 1.));
+    let hits = world.get_hits(&ray);
+
+    assert_eq!(1, world.get_objects().len());
+    assert_eq!(1, hits.len());
+    assert_eq!(2.0, hits[0].get_t());
+}
+
+#[test]
+fn into_group_nests_named_groups() {
+    let contents = "v -1 1 0\nv -1 0 0\nv 1 0 0\nv 1 1 0\n\
+                    g FirstGroup\nf 1 2 3\n\
+                    g SecondGroup\nf 1 3 4\nf 1 2 4\n";
+    let obj = ObjFile::parse(contents).unwrap();
+
+    let group = obj.into_group();
+
+    assert_eq!(2, group.get_children().len());
+    let ray = Ray::new(Point::new(-0.8, 0.5, -2.), Vector::new(0., 0., 1.));
+    assert_eq!(2, group.get_hits(&ray).len());
+}
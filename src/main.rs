@@ -1,10 +1,30 @@
-use rust_tracer::{primitives::Canvas, scenes::three_balls_in_a_room, tracing::Tracer};
+use std::thread;
+
+use rust_tracer::{
+    primitives::Canvas, scenes::three_balls_in_a_room, tone_mapping::AcesToneMapper,
+    tracing::Tracer,
+};
 
 fn main() {
     let mut canvas = Canvas::new(400, 400, None);
-    let (world, camera) = three_balls_in_a_room::build(&canvas);
-    Tracer::trace_world(&world, &camera, &mut canvas);
-    let result = canvas.to_ppm("C:/Users/romea/source/rust-tracer/output.ppm");
+    let (world, camera) = three_balls_in_a_room::build(canvas.get_width(), canvas.get_height());
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    Tracer::trace_world_parallel(
+        &world,
+        &camera,
+        &mut canvas,
+        threads,
+        32,
+        |finished, total| {
+            println!("Rendered tile {} of {}", finished, total);
+        },
+    );
+    let tone_mapper = AcesToneMapper::new();
+    let result = canvas.save("C:/Users/romea/source/rust-tracer/output.hdr", &tone_mapper);
+    if result.is_err() {
+        println!("{:?}", result.err());
+    }
+    let result = canvas.save("C:/Users/romea/source/rust-tracer/output.png", &tone_mapper);
     if result.is_err() {
         println!("{:?}", result.err());
     }
@@ -0,0 +1,175 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Hittable;
+
+const EPSILON: f64 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cone {
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+}
+
+impl Cone {
+    pub fn identity() -> Self {
+        Cone {
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn with_transform(transform: SquareMatrix) -> Self {
+        let mut cone = Cone::identity();
+        cone.set_transform(transform);
+        cone
+    }
+
+    pub fn get_minimum(&self) -> f64 {
+        self.minimum
+    }
+
+    pub fn set_minimum(&mut self, minimum: f64) {
+        self.minimum = minimum;
+    }
+
+    pub fn get_maximum(&self) -> f64 {
+        self.maximum
+    }
+
+    pub fn set_maximum(&mut self, maximum: f64) {
+        self.maximum = maximum;
+    }
+
+    pub fn get_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed;
+    }
+
+    fn check_cap(ray: &Ray, t: f64, radius: f64) -> bool {
+        let x = ray.get_origin().get_x() + t * ray.get_direction().get_x();
+        let z = ray.get_origin().get_z() + t * ray.get_direction().get_z();
+        x * x + z * z <= radius * radius + EPSILON
+    }
+
+    fn add_cap_hits<'a>(&'a self, ray: &Ray, result: &mut Vec<Hit<'a>>) {
+        let direction_y = ray.get_direction().get_y();
+        if !self.closed || direction_y.abs() < EPSILON {
+            return;
+        }
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.get_origin().get_y()) / direction_y;
+            if Cone::check_cap(ray, t, cap.abs()) {
+                result.push(Hit::new(t, self));
+            }
+        }
+    }
+}
+
+impl Hittable for Cone {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let mut result = Vec::new();
+        let ray = ray.transform(&self.inverted);
+        let origin = ray.get_origin();
+        let direction = ray.get_direction();
+        let (ox, oy, oz) = (origin.get_x(), origin.get_y(), origin.get_z());
+        let (dx, dy, dz) = (direction.get_x(), direction.get_y(), direction.get_z());
+
+        let a = dx * dx - dy * dy + dz * dz;
+        let b = 2.0 * ox * dx - 2.0 * oy * dy + 2.0 * oz * dz;
+        let c = ox * ox - oy * oy + oz * oz;
+
+        let mut candidates = Vec::with_capacity(2);
+        if a.abs() < EPSILON {
+            if b.abs() >= EPSILON {
+                candidates.push(-c / (2.0 * b));
+            }
+        } else {
+            let discriminant = b * b - 4.0 * a * c;
+            if discriminant >= 0.0 {
+                let discriminant = discriminant.sqrt();
+                candidates.push((-b - discriminant) / (2.0 * a));
+                candidates.push((-b + discriminant) / (2.0 * a));
+            }
+        }
+        for t in candidates {
+            let y = oy + t * dy;
+            if self.minimum < y && y < self.maximum {
+                result.push(Hit::new(t, self));
+            }
+        }
+
+        self.add_cap_hits(&ray, &mut result);
+        result.sort_unstable_by(|a, b| a.cmp_ignore_nan(b));
+        result
+    }
+
+    fn get_normal(&self, hit_point: &Point) -> Vector {
+        let object_point = &self.inverted * hit_point;
+        let x = object_point.get_x();
+        let y = object_point.get_y();
+        let z = object_point.get_z();
+        let distance = x * x + z * z;
+        let object_normal = if distance < self.maximum * self.maximum && y >= self.maximum - EPSILON
+        {
+            Vector::new(0.0, 1.0, 0.0)
+        } else if distance < self.minimum * self.minimum && y <= self.minimum + EPSILON {
+            Vector::new(0.0, -1.0, 0.0)
+        } else {
+            let mut normal_y = distance.sqrt();
+            if y > 0.0 {
+                normal_y = -normal_y;
+            }
+            Vector::new(x, normal_y, z)
+        };
+        let world_normal = &self.inverted.transpose() * &object_normal;
+        world_normal.get_normal()
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        let radius = self.minimum.abs().max(self.maximum.abs());
+        let object_bounds = BoundingBox::new(
+            Point::new(-radius, self.minimum, -radius),
+            Point::new(radius, self.maximum, radius),
+        );
+        object_bounds.transform(&self.transform)
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/cone_tests.rs"]
+mod tests;
@@ -0,0 +1,117 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Hittable;
+
+const EPSILON: f64 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cube {
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+}
+
+impl Cube {
+    pub fn identity() -> Self {
+        Cube {
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+        }
+    }
+
+    pub fn with_transform(transform: SquareMatrix) -> Self {
+        Cube {
+            inverted: transform.invert(),
+            transform,
+            material: Material::default(),
+        }
+    }
+
+    fn check_axis(origin: f64, direction: f64) -> (f64, f64) {
+        let tmin_numerator = -1.0 - origin;
+        let tmax_numerator = 1.0 - origin;
+        let (tmin, tmax) = if direction.abs() >= EPSILON {
+            (tmin_numerator / direction, tmax_numerator / direction)
+        } else {
+            (
+                tmin_numerator * f64::INFINITY,
+                tmax_numerator * f64::INFINITY,
+            )
+        };
+        if tmin > tmax {
+            (tmax, tmin)
+        } else {
+            (tmin, tmax)
+        }
+    }
+}
+
+impl Hittable for Cube {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let ray = ray.transform(&self.inverted);
+        let origin = ray.get_origin();
+        let direction = ray.get_direction();
+        let (xtmin, xtmax) = Cube::check_axis(origin.get_x(), direction.get_x());
+        let (ytmin, ytmax) = Cube::check_axis(origin.get_y(), direction.get_y());
+        let (ztmin, ztmax) = Cube::check_axis(origin.get_z(), direction.get_z());
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+        if tmin > tmax {
+            return Vec::new();
+        }
+        vec![Hit::new(tmin, self), Hit::new(tmax, self)]
+    }
+
+    fn get_normal(&self, hit_point: &Point) -> Vector {
+        let object_point = &self.inverted * hit_point;
+        let x = object_point.get_x().abs();
+        let y = object_point.get_y().abs();
+        let z = object_point.get_z().abs();
+        let max = x.max(y).max(z);
+        let object_normal = if max == x {
+            Vector::new(object_point.get_x(), 0.0, 0.0)
+        } else if max == y {
+            Vector::new(0.0, object_point.get_y(), 0.0)
+        } else {
+            Vector::new(0.0, 0.0, object_point.get_z())
+        };
+        let world_normal = &self.inverted.transpose() * &object_normal;
+        world_normal.get_normal()
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        let object_bounds =
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        object_bounds.transform(&self.transform)
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/cube_tests.rs"]
+mod tests;
@@ -0,0 +1,166 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Hittable;
+
+const EPSILON: f64 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cylinder {
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+}
+
+impl Cylinder {
+    pub fn identity() -> Self {
+        Cylinder {
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn with_transform(transform: SquareMatrix) -> Self {
+        let mut cylinder = Cylinder::identity();
+        cylinder.set_transform(transform);
+        cylinder
+    }
+
+    pub fn get_minimum(&self) -> f64 {
+        self.minimum
+    }
+
+    pub fn set_minimum(&mut self, minimum: f64) {
+        self.minimum = minimum;
+    }
+
+    pub fn get_maximum(&self) -> f64 {
+        self.maximum
+    }
+
+    pub fn set_maximum(&mut self, maximum: f64) {
+        self.maximum = maximum;
+    }
+
+    pub fn get_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn set_closed(&mut self, closed: bool) {
+        self.closed = closed;
+    }
+
+    fn check_cap(ray: &Ray, t: f64) -> bool {
+        let x = ray.get_origin().get_x() + t * ray.get_direction().get_x();
+        let z = ray.get_origin().get_z() + t * ray.get_direction().get_z();
+        x * x + z * z <= 1.0 + EPSILON
+    }
+
+    fn add_cap_hits<'a>(&'a self, ray: &Ray, result: &mut Vec<Hit<'a>>) {
+        let direction_y = ray.get_direction().get_y();
+        if !self.closed || direction_y.abs() < EPSILON {
+            return;
+        }
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.get_origin().get_y()) / direction_y;
+            if Cylinder::check_cap(ray, t) {
+                result.push(Hit::new(t, self));
+            }
+        }
+    }
+}
+
+impl Hittable for Cylinder {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let mut result = Vec::new();
+        let ray = ray.transform(&self.inverted);
+        let origin = ray.get_origin();
+        let direction = ray.get_direction();
+        let a = direction.get_x() * direction.get_x() + direction.get_z() * direction.get_z();
+
+        if a.abs() >= EPSILON {
+            let b =
+                2.0 * origin.get_x() * direction.get_x() + 2.0 * origin.get_z() * direction.get_z();
+            let c = origin.get_x() * origin.get_x() + origin.get_z() * origin.get_z() - 1.0;
+            let discriminant = b * b - 4.0 * a * c;
+            if discriminant < 0.0 {
+                return result;
+            }
+            let discriminant = discriminant.sqrt();
+            let mut t0 = (-b - discriminant) / (2.0 * a);
+            let mut t1 = (-b + discriminant) / (2.0 * a);
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            for t in [t0, t1] {
+                let y = origin.get_y() + t * direction.get_y();
+                if self.minimum < y && y < self.maximum {
+                    result.push(Hit::new(t, self));
+                }
+            }
+        }
+
+        self.add_cap_hits(&ray, &mut result);
+        result.sort_unstable_by(|a, b| a.cmp_ignore_nan(b));
+        result
+    }
+
+    fn get_normal(&self, hit_point: &Point) -> Vector {
+        let object_point = &self.inverted * hit_point;
+        let x = object_point.get_x();
+        let y = object_point.get_y();
+        let z = object_point.get_z();
+        let distance = x * x + z * z;
+        let object_normal = if distance < 1.0 && y >= self.maximum - EPSILON {
+            Vector::new(0.0, 1.0, 0.0)
+        } else if distance < 1.0 && y <= self.minimum + EPSILON {
+            Vector::new(0.0, -1.0, 0.0)
+        } else {
+            Vector::new(x, 0.0, z)
+        };
+        let world_normal = &self.inverted.transpose() * &object_normal;
+        world_normal.get_normal()
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        let object_bounds = BoundingBox::new(
+            Point::new(-1.0, self.minimum, -1.0),
+            Point::new(1.0, self.maximum, 1.0),
+        );
+        object_bounds.transform(&self.transform)
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/cylinder_tests.rs"]
+mod tests;
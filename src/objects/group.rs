@@ -0,0 +1,141 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Hittable;
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug)]
+pub struct Group {
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+    children: Vec<Box<dyn Hittable>>,
+    local_transforms: Vec<SquareMatrix>,
+    bounds: BoundingBox,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Group {
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+            children: Vec::new(),
+            local_transforms: Vec::new(),
+            bounds: BoundingBox::empty(),
+        }
+    }
+
+    pub fn with_transform(transform: SquareMatrix) -> Self {
+        let mut group = Group::new();
+        group.set_transform(transform);
+        group
+    }
+
+    pub fn add_child<T: Hittable + 'static>(&mut self, child: T) {
+        self.add_boxed_child(Box::new(child));
+    }
+
+    /// Adds a child; one that still has the default material takes the
+    /// group's material.
+    pub fn add_boxed_child(&mut self, mut child: Box<dyn Hittable>) {
+        if child.get_material() == &Material::default() {
+            child.set_material(self.material.clone());
+        }
+        let local_transform = child.get_transform().clone();
+        child.set_transform(&self.transform * &local_transform);
+        self.bounds.merge(&child.get_bounds());
+        self.children.push(child);
+        self.local_transforms.push(local_transform);
+    }
+
+    pub fn get_children(&self) -> &Vec<Box<dyn Hittable>> {
+        &self.children
+    }
+
+    pub fn get_local_transforms(&self) -> &Vec<SquareMatrix> {
+        &self.local_transforms
+    }
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hittable for Group {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let mut result = Vec::new();
+        if !self.bounds.intersects(ray) {
+            return result;
+        }
+        for child in self.children.iter() {
+            result.append(&mut child.get_hits(ray));
+        }
+        result.sort_unstable_by(|a, b| a.cmp_ignore_nan(b));
+        result
+    }
+
+    /// A group has no surface of its own and its hits report the child that
+    /// was hit, so this only approximates: it points away from the centre of
+    /// the group's bounds.
+    fn get_normal(&self, hit_point: &Point) -> Vector {
+        let outward = if self.bounds.is_finite() {
+            hit_point - &self.bounds.get_centroid()
+        } else {
+            hit_point - &Point::new(0.0, 0.0, 0.0)
+        };
+        if outward.len() < EPSILON {
+            Vector::new(0.0, 1.0, 0.0)
+        } else {
+            outward.get_normal()
+        }
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        self.bounds.clone()
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    /// Gives the material to the children that still share the group's
+    /// current one; children with a material of their own keep it.
+    fn set_material(&mut self, material: Material) {
+        for child in self.children.iter_mut() {
+            if child.get_material() == &self.material {
+                child.set_material(material.clone());
+            }
+        }
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        for (child, local_transform) in self.children.iter_mut().zip(self.local_transforms.iter()) {
+            child.set_transform(&transform * local_transform);
+        }
+        self.bounds = BoundingBox::empty();
+        for child in self.children.iter() {
+            self.bounds.merge(&child.get_bounds());
+        }
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/group_tests.rs"]
+mod tests;
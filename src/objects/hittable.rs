@@ -1,11 +1,17 @@
+use std::fmt::Debug;
+
 use crate::{
-    primitives::{Hit, Point, Ray, Vector, SquareMatrix},
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
     shading::Material,
 };
 
-pub trait Hittable {
-    fn get_hits(&self, ray: &Ray) -> Vec<Hit>;
+pub trait Hittable: Debug + Send + Sync {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>>;
     fn get_normal(&self, hit_point: &Point) -> Vector;
+    fn get_normal_at(&self, hit_point: &Point, _hit: &Hit) -> Vector {
+        self.get_normal(hit_point)
+    }
+    fn get_bounds(&self) -> BoundingBox;
     fn get_material(&self) -> &Material;
     fn set_material(&mut self, material: Material);
     fn get_transform(&self) -> &SquareMatrix;
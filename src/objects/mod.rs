@@ -1,5 +1,19 @@
 mod sphere;
 mod hittable;
+mod plane;
+mod cube;
+mod cylinder;
+mod cone;
+mod triangle;
+mod smooth_triangle;
+mod group;
 
 pub use sphere::Sphere;
-pub use hittable::Hittable;
\ No newline at end of file
+pub use hittable::Hittable;
+pub use plane::Plane;
+pub use cube::Cube;
+pub use cylinder::Cylinder;
+pub use cone::Cone;
+pub use triangle::Triangle;
+pub use smooth_triangle::SmoothTriangle;
+pub use group::Group;
\ No newline at end of file
@@ -0,0 +1,83 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Hittable;
+
+const EPSILON: f64 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plane {
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+}
+
+impl Plane {
+    pub fn identity() -> Self {
+        Plane {
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+        }
+    }
+
+    pub fn with_transform(transform: SquareMatrix) -> Self {
+        Plane {
+            inverted: transform.invert(),
+            transform,
+            material: Material::default(),
+        }
+    }
+}
+
+impl Hittable for Plane {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let ray = ray.transform(&self.inverted);
+        let direction_y = ray.get_direction().get_y();
+        if direction_y.abs() < EPSILON {
+            return Vec::new();
+        }
+        vec![Hit::new(-ray.get_origin().get_y() / direction_y, self)]
+    }
+
+    fn get_normal(&self, _hit_point: &Point) -> Vector {
+        let object_normal = Vector::new(0.0, 1.0, 0.0);
+        let world_normal = &self.inverted.transpose() * &object_normal;
+        world_normal.get_normal()
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        let object_bounds = BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0.0, f64::INFINITY),
+        );
+        object_bounds.transform(&self.transform)
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/plane_tests.rs"]
+mod tests;
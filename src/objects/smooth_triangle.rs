@@ -0,0 +1,122 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::{triangle, Hittable};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmoothTriangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        let e1 = &p2 - &p1;
+        let e2 = &p3 - &p1;
+        SmoothTriangle {
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1,
+            e2,
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+        }
+    }
+
+    pub fn get_p1(&self) -> &Point {
+        &self.p1
+    }
+
+    pub fn get_p2(&self) -> &Point {
+        &self.p2
+    }
+
+    pub fn get_p3(&self) -> &Point {
+        &self.p3
+    }
+
+    pub fn get_n1(&self) -> &Vector {
+        &self.n1
+    }
+
+    pub fn get_n2(&self) -> &Vector {
+        &self.n2
+    }
+
+    pub fn get_n3(&self) -> &Vector {
+        &self.n3
+    }
+
+    fn interpolate_normal(&self, u: f64, v: f64) -> Vector {
+        let weighted = &(&(&self.n2 * u) + &(&self.n3 * v)) + &(&self.n1 * (1.0 - u - v));
+        let world_normal = &self.inverted.transpose() * &weighted;
+        world_normal.get_normal()
+    }
+}
+
+impl Hittable for SmoothTriangle {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let ray = ray.transform(&self.inverted);
+        match triangle::intersect(&ray, &self.p1, &self.e1, &self.e2) {
+            Some((t, u, v)) => vec![Hit::with_uv(t, self, u, v)],
+            None => Vec::new(),
+        }
+    }
+
+    fn get_normal(&self, _hit_point: &Point) -> Vector {
+        self.interpolate_normal(1.0 / 3.0, 1.0 / 3.0)
+    }
+
+    fn get_normal_at(&self, _hit_point: &Point, hit: &Hit) -> Vector {
+        self.interpolate_normal(hit.get_u(), hit.get_v())
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        let mut object_bounds = BoundingBox::empty();
+        object_bounds.add_point(&self.p1);
+        object_bounds.add_point(&self.p2);
+        object_bounds.add_point(&self.p3);
+        object_bounds.transform(&self.transform)
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/smooth_triangle_tests.rs"]
+mod tests;
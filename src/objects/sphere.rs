@@ -1,5 +1,5 @@
 use crate::{
-    primitives::{Hit, Point, Ray, SquareMatrix, Vector},
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
     shading::Material,
 };
 
@@ -38,7 +38,7 @@ impl Sphere {
 }
 
 impl Hittable for Sphere {
-    fn get_hits(&self, ray: &Ray) -> Vec<Hit> {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
         let mut result = Vec::new();
         let ray = ray.transform(&self.inverted);
         let sphere_to_ray = &(ray.get_origin() - &Point::new(0., 0., 0.));
@@ -67,7 +67,12 @@ impl Hittable for Sphere {
         world_normal.get_normal()
     }
 
-    
+    fn get_bounds(&self) -> BoundingBox {
+        let object_bounds =
+            BoundingBox::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        object_bounds.transform(&self.transform)
+    }
+
     fn get_material(&self) -> &Material {
         &self.material
     }
@@ -0,0 +1,131 @@
+use std::f64::consts::SQRT_2;
+
+use crate::{
+    objects::Hittable,
+    primitives::{Point, Ray, SquareMatrix, Vector},
+};
+
+use super::Cone;
+
+#[test]
+fn identity_is_infinite_and_open() {
+    let cone = Cone::identity();
+
+    assert_eq!(f64::NEG_INFINITY, cone.get_minimum());
+    assert_eq!(f64::INFINITY, cone.get_maximum());
+    assert!(!cone.get_closed());
+}
+
+#[test]
+fn get_hits_returns_hit_points() {
+    let cone = Cone::identity();
+    let cases = [
+        (Point::new(0., 0., -5.), Vector::new(0., 0., 1.), 5., 5.),
+        (
+            Point::new(0., 0., -5.),
+            Vector::new(1., 1., 1.),
+            8.660254037844386,
+            8.660254037844386,
+        ),
+        (
+            Point::new(1., 1., -5.),
+            Vector::new(-0.5, -1., 1.),
+            4.550055679356349,
+            49.449944320643645,
+        ),
+    ];
+
+    for (origin, direction, t0, t1) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        let hits = cone.get_hits(&ray);
+
+        assert_eq!(2, hits.len());
+        assert!((hits[0].get_t() - t0).abs() < 1e-5);
+        assert!((hits[1].get_t() - t1).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn get_hits_parallel_to_one_half_returns_one_point() {
+    let cone = Cone::identity();
+    let ray = Ray::new(
+        Point::new(0., 0., -1.),
+        Vector::new(0., 1., 1.).get_normal(),
+    );
+
+    let hits = cone.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert!((hits[0].get_t() - 0.3535533905932738).abs() < 1e-5);
+}
+
+#[test]
+fn get_hits_on_closed_cone_includes_caps() {
+    let mut cone = Cone::identity();
+    cone.set_minimum(-0.5);
+    cone.set_maximum(0.5);
+    cone.set_closed(true);
+    let cases = [
+        (Point::new(0., 0., -5.), Vector::new(0., 1., 0.), 0),
+        (Point::new(0., 0., -0.25), Vector::new(0., 1., 1.), 2),
+        (Point::new(0., 0., -0.25), Vector::new(0., 1., 0.), 4),
+    ];
+
+    for (origin, direction, count) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        assert_eq!(count, cone.get_hits(&ray).len());
+    }
+}
+
+#[test]
+fn get_hits_on_open_truncated_cone_ignores_caps() {
+    let mut cone = Cone::identity();
+    cone.set_minimum(-0.5);
+    cone.set_maximum(0.5);
+    let ray = Ray::new(Point::new(0., 0., -0.25), Vector::new(0., 1., 0.));
+
+    assert_eq!(2, cone.get_hits(&ray).len());
+}
+
+#[test]
+fn normal_on_side_computes_correctly() {
+    let cone = Cone::identity();
+    let cases = [
+        (Point::new(1., 1., 1.), Vector::new(1., -SQRT_2, 1.)),
+        (Point::new(-1., -1., 0.), Vector::new(-1., 1., 0.)),
+    ];
+
+    for (point, expected) in cases {
+        assert_eq!(cone.get_normal(&point), expected.get_normal());
+    }
+}
+
+#[test]
+fn normal_on_caps_computes_correctly() {
+    let mut cone = Cone::identity();
+    cone.set_minimum(-1.0);
+    cone.set_maximum(2.0);
+    cone.set_closed(true);
+
+    assert_eq!(
+        cone.get_normal(&Point::new(0.5, 2., 0.)),
+        Vector::new(0., 1., 0.)
+    );
+    assert_eq!(
+        cone.get_normal(&Point::new(0., -1., 0.5)),
+        Vector::new(0., -1., 0.)
+    );
+}
+
+#[test]
+fn get_hits_computes_on_transformed_cone() {
+    let cone = Cone::with_transform(SquareMatrix::translation(0., 0., 1.));
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+
+    let hits = cone.get_hits(&ray);
+
+    assert_eq!(2, hits.len());
+    assert!((hits[0].get_t() - 6.).abs() < 1e-5);
+}
@@ -0,0 +1,101 @@
+use std::f64::consts::PI;
+
+use crate::{
+    objects::Hittable,
+    primitives::{Hit, Point, Ray, SquareMatrix, Vector},
+};
+
+use super::Cube;
+
+#[test]
+fn get_hits_on_each_face_returns_two_points() {
+    let cube = Cube::identity();
+    let cases = [
+        (Point::new(5., 0.5, 0.), Vector::new(-1., 0., 0.), 4., 6.),
+        (Point::new(-5., 0.5, 0.), Vector::new(1., 0., 0.), 4., 6.),
+        (Point::new(0.5, 5., 0.), Vector::new(0., -1., 0.), 4., 6.),
+        (Point::new(0.5, -5., 0.), Vector::new(0., 1., 0.), 4., 6.),
+        (Point::new(0.5, 0., 5.), Vector::new(0., 0., -1.), 4., 6.),
+        (Point::new(0.5, 0., -5.), Vector::new(0., 0., 1.), 4., 6.),
+        (Point::new(0., 0.5, 0.), Vector::new(0., 0., 1.), -1., 1.),
+    ];
+
+    for (origin, direction, t1, t2) in cases {
+        let ray = Ray::new(origin, direction);
+
+        let hits = cube.get_hits(&ray);
+
+        assert_eq!(2, hits.len());
+        assert_eq!(hits[0], Hit::new(t1, &cube));
+        assert_eq!(hits[1], Hit::new(t2, &cube));
+    }
+}
+
+#[test]
+fn get_hits_misses_cube() {
+    let cube = Cube::identity();
+    let cases = [
+        (Point::new(-2., 0., 0.), Vector::new(0.2673, 0.5345, 0.8018)),
+        (Point::new(0., -2., 0.), Vector::new(0.8018, 0.2673, 0.5345)),
+        (Point::new(0., 0., -2.), Vector::new(0.5345, 0.8018, 0.2673)),
+        (Point::new(2., 0., 2.), Vector::new(0., 0., -1.)),
+        (Point::new(0., 2., 2.), Vector::new(0., -1., 0.)),
+        (Point::new(2., 2., 0.), Vector::new(-1., 0., 0.)),
+    ];
+
+    for (origin, direction) in cases {
+        let ray = Ray::new(origin, direction);
+
+        assert_eq!(0, cube.get_hits(&ray).len());
+    }
+}
+
+#[test]
+fn get_hits_computes_on_transformed_cube() {
+    let cube = Cube::with_transform(SquareMatrix::scaling(2., 2., 2.));
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+
+    let hits = cube.get_hits(&ray);
+
+    assert_eq!(2, hits.len());
+    assert_eq!(hits[0], Hit::new(3.0, &cube));
+    assert_eq!(hits[1], Hit::new(7.0, &cube));
+}
+
+#[test]
+fn normal_on_surface_computes_correctly() {
+    let cube = Cube::identity();
+    let cases = [
+        (Point::new(1., 0.5, -0.8), Vector::new(1., 0., 0.)),
+        (Point::new(-1., -0.2, 0.9), Vector::new(-1., 0., 0.)),
+        (Point::new(-0.4, 1., -0.1), Vector::new(0., 1., 0.)),
+        (Point::new(0.3, -1., -0.7), Vector::new(0., -1., 0.)),
+        (Point::new(-0.6, 0.3, 1.), Vector::new(0., 0., 1.)),
+        (Point::new(0.4, 0.4, -1.), Vector::new(0., 0., -1.)),
+        (Point::new(1., 1., 1.), Vector::new(1., 0., 0.)),
+        (Point::new(-1., -1., -1.), Vector::new(-1., 0., 0.)),
+    ];
+
+    for (point, expected) in cases {
+        assert_eq!(cube.get_normal(&point), expected);
+    }
+}
+
+#[test]
+fn normal_on_transformed_cube_computes_correctly() {
+    let transform = &SquareMatrix::translation(0., 1., 0.) * &SquareMatrix::rotation_y(PI / 2.);
+    let cube = Cube::with_transform(transform);
+
+    let normal = cube.get_normal(&Point::new(0., 1., -1.));
+
+    assert_eq!(normal, Vector::new(0., 0., -1.));
+}
+
+#[test]
+fn normal_on_scaled_cube_is_normalized() {
+    let cube = Cube::with_transform(SquareMatrix::scaling(4., 0.5, 2.));
+
+    let normal = cube.get_normal(&Point::new(0., 0.5, 0.));
+
+    assert_eq!(normal, Vector::new(0., 1., 0.));
+}
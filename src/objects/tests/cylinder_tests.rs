@@ -0,0 +1,156 @@
+use crate::{
+    objects::Hittable,
+    primitives::{Hit, Point, Ray, SquareMatrix, Vector},
+};
+
+use super::Cylinder;
+
+fn truncated(closed: bool) -> Cylinder {
+    let mut cylinder = Cylinder::identity();
+    cylinder.set_minimum(1.0);
+    cylinder.set_maximum(2.0);
+    cylinder.set_closed(closed);
+    cylinder
+}
+
+#[test]
+fn identity_is_infinite_and_open() {
+    let cylinder = Cylinder::identity();
+
+    assert_eq!(f64::NEG_INFINITY, cylinder.get_minimum());
+    assert_eq!(f64::INFINITY, cylinder.get_maximum());
+    assert!(!cylinder.get_closed());
+}
+
+#[test]
+fn get_hits_misses_cylinder() {
+    let cylinder = Cylinder::identity();
+    let cases = [
+        (Point::new(1., 0., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0., 0., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0., 0., -5.), Vector::new(1., 1., 1.)),
+    ];
+
+    for (origin, direction) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        assert_eq!(0, cylinder.get_hits(&ray).len());
+    }
+}
+
+#[test]
+fn get_hits_returns_hit_points() {
+    let cylinder = Cylinder::identity();
+    let cases = [
+        (Point::new(1., 0., -5.), Vector::new(0., 0., 1.), 5., 5.),
+        (Point::new(0., 0., -5.), Vector::new(0., 0., 1.), 4., 6.),
+        (
+            Point::new(0.5, 0., -5.),
+            Vector::new(0.1, 1., 1.),
+            6.80798191702732,
+            7.088723439378861,
+        ),
+    ];
+
+    for (origin, direction, t0, t1) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        let hits = cylinder.get_hits(&ray);
+
+        assert_eq!(2, hits.len());
+        assert!((hits[0].get_t() - t0).abs() < 1e-5);
+        assert!((hits[1].get_t() - t1).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn get_hits_on_truncated_cylinder_respects_extents() {
+    let cylinder = truncated(false);
+    let cases = [
+        (Point::new(0., 1.5, 0.), Vector::new(0.1, 1., 0.), 0),
+        (Point::new(0., 3., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 0., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 2., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 1., -5.), Vector::new(0., 0., 1.), 0),
+        (Point::new(0., 1.5, -2.), Vector::new(0., 0., 1.), 2),
+    ];
+
+    for (origin, direction, count) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        assert_eq!(count, cylinder.get_hits(&ray).len());
+    }
+}
+
+#[test]
+fn get_hits_on_closed_cylinder_includes_caps() {
+    let cylinder = truncated(true);
+    let cases = [
+        (Point::new(0., 3., 0.), Vector::new(0., -1., 0.)),
+        (Point::new(0., 3., -2.), Vector::new(0., -1., 2.)),
+        (Point::new(0., 4., -2.), Vector::new(0., -1., 1.)),
+        (Point::new(0., 0., -2.), Vector::new(0., 1., 2.)),
+        (Point::new(0., -1., -2.), Vector::new(0., 1., 1.)),
+    ];
+
+    for (origin, direction) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        assert_eq!(2, cylinder.get_hits(&ray).len());
+    }
+}
+
+#[test]
+fn get_hits_are_sorted() {
+    let cylinder = truncated(true);
+    let ray = Ray::new(Point::new(0., 3., 0.), Vector::new(0., -1., 0.));
+
+    let hits = cylinder.get_hits(&ray);
+
+    assert_eq!(hits[0], Hit::new(1.0, &cylinder));
+    assert_eq!(hits[1], Hit::new(2.0, &cylinder));
+}
+
+#[test]
+fn normal_on_side_computes_correctly() {
+    let cylinder = Cylinder::identity();
+    let cases = [
+        (Point::new(1., 0., 0.), Vector::new(1., 0., 0.)),
+        (Point::new(0., 5., -1.), Vector::new(0., 0., -1.)),
+        (Point::new(0., -2., 1.), Vector::new(0., 0., 1.)),
+        (Point::new(-1., 1., 0.), Vector::new(-1., 0., 0.)),
+    ];
+
+    for (point, expected) in cases {
+        assert_eq!(cylinder.get_normal(&point), expected);
+    }
+}
+
+#[test]
+fn normal_on_caps_computes_correctly() {
+    let cylinder = truncated(true);
+    let cases = [
+        (Point::new(0., 1., 0.), Vector::new(0., -1., 0.)),
+        (Point::new(0.5, 1., 0.), Vector::new(0., -1., 0.)),
+        (Point::new(0., 1., 0.5), Vector::new(0., -1., 0.)),
+        (Point::new(0., 2., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0.5, 2., 0.), Vector::new(0., 1., 0.)),
+        (Point::new(0., 2., 0.5), Vector::new(0., 1., 0.)),
+    ];
+
+    for (point, expected) in cases {
+        assert_eq!(cylinder.get_normal(&point), expected);
+    }
+}
+
+#[test]
+fn get_hits_computes_on_transformed_cylinder() {
+    let cylinder = Cylinder::with_transform(SquareMatrix::scaling(2., 1., 2.));
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+
+    let hits = cylinder.get_hits(&ray);
+
+    assert_eq!(2, hits.len());
+    assert_eq!(hits[0], Hit::new(3.0, &cylinder));
+    assert_eq!(hits[1], Hit::new(7.0, &cylinder));
+}
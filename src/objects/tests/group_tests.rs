@@ -0,0 +1,173 @@
+use std::f64::consts::PI;
+
+use crate::{
+    objects::{Hittable, Sphere},
+    primitives::{Point, Ray, RgbColor, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Group;
+
+#[test]
+fn new_group_is_empty_with_identity_transform() {
+    let group = Group::new();
+
+    assert!(group.get_children().is_empty());
+    assert_eq!(group.get_transform(), &SquareMatrix::identity(4));
+}
+
+#[test]
+fn add_child_composes_group_transform() {
+    let mut group = Group::with_transform(SquareMatrix::translation(1., 0., 0.));
+    let local = SquareMatrix::scaling(2., 2., 2.);
+
+    group.add_child(Sphere::with_transform(local.clone()));
+
+    let expected = &SquareMatrix::translation(1., 0., 0.) * &local;
+    assert_eq!(group.get_children()[0].get_transform(), &expected);
+    assert_eq!(group.get_local_transforms()[0], local);
+}
+
+#[test]
+fn get_hits_on_empty_group_returns_nothing() {
+    let group = Group::new();
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+
+    assert!(group.get_hits(&ray).is_empty());
+}
+
+#[test]
+fn get_hits_returns_sorted_hits_from_children() {
+    let mut group = Group::new();
+    group.add_child(Sphere::identity());
+    group.add_child(Sphere::with_transform(SquareMatrix::translation(
+        0., 0., -3.,
+    )));
+    group.add_child(Sphere::with_transform(SquareMatrix::translation(
+        5., 0., 0.,
+    )));
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+
+    let hits = group.get_hits(&ray);
+
+    let times: Vec<f64> = hits.iter().map(|hit| hit.get_t()).collect();
+    assert_eq!(vec![1., 3., 4., 6.], times);
+    let second = &group.get_children()[1];
+    assert!(std::ptr::addr_eq(hits[0].get_object(), second.as_ref()));
+    assert!(std::ptr::addr_eq(hits[1].get_object(), second.as_ref()));
+}
+
+#[test]
+fn get_hits_on_transformed_group_uses_composed_transform() {
+    let mut group = Group::with_transform(SquareMatrix::scaling(2., 2., 2.));
+    group.add_child(Sphere::with_transform(SquareMatrix::translation(
+        5., 0., 0.,
+    )));
+    let ray = Ray::new(Point::new(10., 0., -10.), Vector::new(0., 0., 1.));
+
+    assert_eq!(2, group.get_hits(&ray).len());
+}
+
+#[test]
+fn set_transform_after_adding_children_updates_them() {
+    let mut group = Group::new();
+    group.add_child(Sphere::with_transform(SquareMatrix::translation(
+        5., 0., 0.,
+    )));
+
+    group.set_transform(SquareMatrix::scaling(2., 2., 2.));
+    let ray = Ray::new(Point::new(10., 0., -10.), Vector::new(0., 0., 1.));
+
+    assert_eq!(2, group.get_hits(&ray).len());
+    assert_eq!(
+        group.get_children()[0].get_transform(),
+        &(&SquareMatrix::scaling(2., 2., 2.) * &SquareMatrix::translation(5., 0., 0.))
+    );
+}
+
+#[test]
+fn normal_on_nested_child_converts_through_parents() {
+    let mut inner = Group::with_transform(SquareMatrix::scaling(1., 2., 3.));
+    inner.add_child(Sphere::with_transform(SquareMatrix::translation(
+        5., 0., 0.,
+    )));
+    let mut outer = Group::with_transform(SquareMatrix::rotation_y(PI / 2.));
+    outer.add_child(inner);
+
+    let child = &outer.get_children()[0];
+    let ray = Ray::new(Point::new(1.7321, 1.1547, -10.), Vector::new(0., 0., 1.));
+    let hits = child.get_hits(&ray);
+    let normal = hits[0]
+        .get_object()
+        .get_normal(&Point::new(1.7321, 1.1547, -5.5774));
+
+    assert!((normal.get_x() - 0.2857).abs() < 1e-4);
+    assert!((normal.get_y() - 0.4286).abs() < 1e-4);
+    assert!((normal.get_z() + 0.8571).abs() < 1e-4);
+}
+
+#[test]
+fn set_material_applies_to_children() {
+    let mut group = Group::new();
+    group.add_child(Sphere::identity());
+    let mut material = Material::default();
+    material.set_color(RgbColor::new(0.1, 0.2, 0.3));
+
+    group.set_material(material.clone());
+
+    assert_eq!(group.get_material(), &material);
+    assert_eq!(group.get_children()[0].get_material(), &material);
+}
+
+#[test]
+fn set_material_keeps_materials_of_children() {
+    let mut table = Group::new();
+    table.add_child(Sphere::identity());
+    let mut item = Sphere::with_transform(SquareMatrix::translation(0., 2., 0.));
+    let mut item_material = Material::default();
+    item_material.set_color(RgbColor::new(0.9, 0.1, 0.1));
+    item.set_material(item_material.clone());
+    table.add_child(item);
+    let mut wood = Material::default();
+    wood.set_color(RgbColor::new(0.5, 0.3, 0.1));
+
+    table.set_material(wood.clone());
+
+    assert_eq!(table.get_children()[0].get_material(), &wood);
+    assert_eq!(table.get_children()[1].get_material(), &item_material);
+}
+
+#[test]
+fn children_sharing_group_material_follow_later_changes() {
+    let mut group = Group::new();
+    let mut first = Material::default();
+    first.set_color(RgbColor::new(0.1, 0.2, 0.3));
+    let mut second = Material::default();
+    second.set_color(RgbColor::new(0.3, 0.2, 0.1));
+
+    group.set_material(first);
+    group.add_child(Sphere::identity());
+    group.set_material(second.clone());
+
+    assert_eq!(group.get_children()[0].get_material(), &second);
+}
+
+#[test]
+fn get_normal_on_group_points_away_from_its_centre() {
+    let mut group = Group::new();
+    group.add_child(Sphere::with_transform(SquareMatrix::translation(
+        5., 0., 0.,
+    )));
+
+    let normal = group.get_normal(&Point::new(5., 0., -1.));
+
+    assert_eq!(Vector::new(0., 0., -1.), normal);
+    assert_eq!(
+        Vector::new(0., 1., 0.),
+        group.get_normal(&Point::new(5., 0., 0.))
+    );
+    assert_eq!(
+        Vector::new(1., 0., 0.),
+        Group::new().get_normal(&Point::new(2., 0., 0.))
+    );
+}
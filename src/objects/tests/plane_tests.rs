@@ -0,0 +1,112 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use crate::{
+    objects::Hittable,
+    primitives::{Hit, Point, Ray, SquareMatrix, Vector},
+};
+
+use super::Plane;
+
+#[test]
+fn get_hits_parallel_ray_misses_plane() {
+    let plane = Plane::identity();
+    let ray = Ray::new(Point::new(0., 10., 0.), Vector::new(0., 0., 1.));
+
+    let hits = plane.get_hits(&ray);
+
+    assert_eq!(0, hits.len());
+}
+
+#[test]
+fn get_hits_coplanar_ray_misses_plane() {
+    let plane = Plane::identity();
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+
+    let hits = plane.get_hits(&ray);
+
+    assert_eq!(0, hits.len());
+}
+
+#[test]
+fn get_hits_nearly_parallel_ray_misses_plane() {
+    let plane = Plane::identity();
+    let ray = Ray::new(Point::new(0., 1., 0.), Vector::new(0., 1e-9, 1.));
+
+    let hits = plane.get_hits(&ray);
+
+    assert_eq!(0, hits.len());
+}
+
+#[test]
+fn get_hits_from_above_returns_one_point() {
+    let plane = Plane::identity();
+    let ray = Ray::new(Point::new(0., 1., 0.), Vector::new(0., -1., 0.));
+
+    let hits = plane.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert_eq!(hits[0], Hit::new(1.0, &plane));
+}
+
+#[test]
+fn get_hits_from_below_returns_one_point() {
+    let plane = Plane::identity();
+    let ray = Ray::new(Point::new(0., -1., 0.), Vector::new(0., 1., 0.));
+
+    let hits = plane.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert_eq!(hits[0], Hit::new(1.0, &plane));
+}
+
+#[test]
+fn get_hits_computes_on_transformed_plane() {
+    let plane = Plane::with_transform(SquareMatrix::translation(0., -2., 0.));
+    let ray = Ray::new(Point::new(0., 3., 0.), Vector::new(0., -1., 0.));
+
+    let hits = plane.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert_eq!(hits[0], Hit::new(5.0, &plane));
+}
+
+#[test]
+fn normal_is_constant_everywhere() {
+    let plane = Plane::identity();
+
+    let expected = Vector::new(0., 1., 0.);
+    assert_eq!(plane.get_normal(&Point::new(0., 0., 0.)), expected);
+    assert_eq!(plane.get_normal(&Point::new(10., 0., -10.)), expected);
+    assert_eq!(plane.get_normal(&Point::new(-5., 0., 150.)), expected);
+}
+
+#[test]
+fn normal_on_rotated_plane_computes_correctly() {
+    let plane = Plane::with_transform(SquareMatrix::rotation_x(PI / 2.));
+
+    let normal = plane.get_normal(&Point::new(0., 0., 0.));
+
+    assert_eq!(normal, Vector::new(0., 0., 1.));
+}
+
+#[test]
+fn normal_on_transformed_plane_is_normalized() {
+    let transform = &SquareMatrix::rotation_z(PI / 4.) * &SquareMatrix::scaling(3., 0.5, 2.);
+    let plane = Plane::with_transform(transform);
+
+    let normal = plane.get_normal(&Point::new(0., 0., 0.));
+
+    assert_eq!(normal, Vector::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.));
+    assert!((normal.len() - 1.).abs() < 1e-9);
+}
+
+#[test]
+fn set_transform_updates_inverse() {
+    let mut plane = Plane::identity();
+    let transform = SquareMatrix::translation(1., 2., 3.);
+
+    plane.set_transform(transform.clone());
+
+    assert_eq!(plane.get_transform(), &transform);
+    assert_eq!(plane.get_transform_inverted(), &transform.invert());
+}
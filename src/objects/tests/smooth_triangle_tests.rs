@@ -0,0 +1,62 @@
+use crate::{
+    objects::Hittable,
+    primitives::{Hit, Point, Ray, Vector},
+};
+
+use super::SmoothTriangle;
+
+fn smooth_triangle() -> SmoothTriangle {
+    SmoothTriangle::new(
+        Point::new(0., 1., 0.),
+        Point::new(-1., 0., 0.),
+        Point::new(1., 0., 0.),
+        Vector::new(0., 1., 0.),
+        Vector::new(-1., 0., 0.),
+        Vector::new(1., 0., 0.),
+    )
+}
+
+#[test]
+fn new_stores_vertices_and_normals() {
+    let triangle = smooth_triangle();
+
+    assert_eq!(triangle.get_p1(), &Point::new(0., 1., 0.));
+    assert_eq!(triangle.get_p2(), &Point::new(-1., 0., 0.));
+    assert_eq!(triangle.get_p3(), &Point::new(1., 0., 0.));
+    assert_eq!(triangle.get_n1(), &Vector::new(0., 1., 0.));
+    assert_eq!(triangle.get_n2(), &Vector::new(-1., 0., 0.));
+    assert_eq!(triangle.get_n3(), &Vector::new(1., 0., 0.));
+}
+
+#[test]
+fn get_hits_stores_barycentrics() {
+    let triangle = smooth_triangle();
+    let ray = Ray::new(Point::new(-0.2, 0.3, -2.), Vector::new(0., 0., 1.));
+
+    let hits = triangle.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert!((hits[0].get_u() - 0.45).abs() < 1e-6);
+    assert!((hits[0].get_v() - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn get_normal_at_interpolates_vertex_normals() {
+    let triangle = smooth_triangle();
+    let hit = Hit::with_uv(1., &triangle, 0.45, 0.25);
+
+    let normal = triangle.get_normal_at(&Point::new(0., 0., 0.), &hit);
+
+    assert_eq!(normal, Vector::new(-0.5547, 0.83205, 0.));
+}
+
+#[test]
+fn hit_record_uses_interpolated_normal() {
+    let triangle = smooth_triangle();
+    let ray = Ray::new(Point::new(-0.2, 0.3, -2.), Vector::new(0., 0., 1.));
+
+    let hits = triangle.get_hits(&ray);
+    let record = hits[0].get_hit_record(&ray, &hits);
+
+    assert_eq!(record.get_normal(), &Vector::new(-0.5547, 0.83205, 0.));
+}
@@ -1,4 +1,4 @@
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
 
 use crate::{
     objects::Hittable,
@@ -92,9 +92,9 @@ fn normal_on_arbitrary_point_computes_correctly() {
 #[test]
 fn normal_on_translated_sphere_computes_correctly() {
     let sphere = Sphere::with_transform(SquareMatrix::translation(0.0, 1.0, 0.0));
-    let normal = sphere.get_normal(&Point::new(0.0, 1.70711, -0.70711));
+    let normal = sphere.get_normal(&Point::new(0.0, 1.0 + FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
 
-    assert_eq!(normal, Vector::new(0.0, 0.7071067, -0.7071067));
+    assert_eq!(normal, Vector::new(0.0, FRAC_1_SQRT_2, -FRAC_1_SQRT_2));
 }
 
 #[test]
@@ -0,0 +1,91 @@
+use crate::{
+    objects::Hittable,
+    primitives::{Hit, Point, Ray, SquareMatrix, Vector},
+};
+
+use super::Triangle;
+
+fn triangle() -> Triangle {
+    Triangle::new(
+        Point::new(0., 1., 0.),
+        Point::new(-1., 0., 0.),
+        Point::new(1., 0., 0.),
+    )
+}
+
+#[test]
+fn new_precomputes_edges_and_normal() {
+    let triangle = triangle();
+
+    assert_eq!(triangle.get_p1(), &Point::new(0., 1., 0.));
+    assert_eq!(triangle.get_p2(), &Point::new(-1., 0., 0.));
+    assert_eq!(triangle.get_p3(), &Point::new(1., 0., 0.));
+    assert_eq!(triangle.get_e1(), &Vector::new(-1., -1., 0.));
+    assert_eq!(triangle.get_e2(), &Vector::new(1., -1., 0.));
+    assert_eq!(triangle.normal, Vector::new(0., 0., -1.));
+}
+
+#[test]
+fn normal_is_constant_everywhere() {
+    let triangle = triangle();
+
+    assert_eq!(
+        triangle.get_normal(&Point::new(0., 0.5, 0.)),
+        triangle.normal
+    );
+    assert_eq!(
+        triangle.get_normal(&Point::new(-0.5, 0.75, 0.)),
+        triangle.normal
+    );
+    assert_eq!(
+        triangle.get_normal(&Point::new(0.5, 0.25, 0.)),
+        triangle.normal
+    );
+}
+
+#[test]
+fn get_hits_parallel_ray_misses_triangle() {
+    let triangle = triangle();
+    let ray = Ray::new(Point::new(0., -1., -2.), Vector::new(0., 1., 0.));
+
+    assert_eq!(0, triangle.get_hits(&ray).len());
+}
+
+#[test]
+fn get_hits_misses_each_edge() {
+    let triangle = triangle();
+    let origins = [
+        Point::new(1., 1., -2.),
+        Point::new(-1., 1., -2.),
+        Point::new(0., -1., -2.),
+    ];
+
+    for origin in origins {
+        let ray = Ray::new(origin, Vector::new(0., 0., 1.));
+
+        assert_eq!(0, triangle.get_hits(&ray).len());
+    }
+}
+
+#[test]
+fn get_hits_returns_one_point_with_barycentrics() {
+    let triangle = triangle();
+    let ray = Ray::new(Point::new(0., 0.5, -2.), Vector::new(0., 0., 1.));
+
+    let hits = triangle.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert_eq!(hits[0], Hit::with_uv(2.0, &triangle, 0.25, 0.25));
+}
+
+#[test]
+fn get_hits_computes_on_transformed_triangle() {
+    let mut triangle = triangle();
+    triangle.set_transform(SquareMatrix::translation(0., 0., 3.));
+    let ray = Ray::new(Point::new(0., 0.5, -2.), Vector::new(0., 0., 1.));
+
+    let hits = triangle.get_hits(&ray);
+
+    assert_eq!(1, hits.len());
+    assert_eq!(5.0, hits[0].get_t());
+}
@@ -0,0 +1,134 @@
+use crate::{
+    primitives::{BoundingBox, Hit, Point, Ray, SquareMatrix, Vector},
+    shading::Material,
+};
+
+use super::Hittable;
+
+const EPSILON: f64 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Triangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = &p2 - &p1;
+        let e2 = &p3 - &p1;
+        let normal = (&e2 ^ &e1).get_normal();
+        Triangle {
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal,
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+            material: Material::default(),
+        }
+    }
+
+    pub fn get_p1(&self) -> &Point {
+        &self.p1
+    }
+
+    pub fn get_p2(&self) -> &Point {
+        &self.p2
+    }
+
+    pub fn get_p3(&self) -> &Point {
+        &self.p3
+    }
+
+    pub fn get_e1(&self) -> &Vector {
+        &self.e1
+    }
+
+    pub fn get_e2(&self) -> &Vector {
+        &self.e2
+    }
+}
+
+/// Möller–Trumbore intersection, returning `t` and the barycentric weights of `p2` and `p3`.
+pub(super) fn intersect(
+    ray: &Ray,
+    p1: &Point,
+    e1: &Vector,
+    e2: &Vector,
+) -> Option<(f64, f64, f64)> {
+    let dir_cross_e2 = ray.get_direction() ^ e2;
+    let determinant = e1 * &dir_cross_e2;
+    if determinant.abs() < EPSILON {
+        return None;
+    }
+    let f = 1.0 / determinant;
+    let p1_to_origin = ray.get_origin() - p1;
+    let u = f * (&p1_to_origin * &dir_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let origin_cross_e1 = &p1_to_origin ^ e1;
+    let v = f * (ray.get_direction() * &origin_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    Some((f * (e2 * &origin_cross_e1), u, v))
+}
+
+impl Hittable for Triangle {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let ray = ray.transform(&self.inverted);
+        match intersect(&ray, &self.p1, &self.e1, &self.e2) {
+            Some((t, u, v)) => vec![Hit::with_uv(t, self, u, v)],
+            None => Vec::new(),
+        }
+    }
+
+    fn get_normal(&self, _hit_point: &Point) -> Vector {
+        let world_normal = &self.inverted.transpose() * &self.normal;
+        world_normal.get_normal()
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        let mut object_bounds = BoundingBox::empty();
+        object_bounds.add_point(&self.p1);
+        object_bounds.add_point(&self.p2);
+        object_bounds.add_point(&self.p3);
+        object_bounds.transform(&self.transform)
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/triangle_tests.rs"]
+mod tests;
@@ -0,0 +1,120 @@
+use super::{Point, Ray, SquareMatrix};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundingBox {
+    minimum: Point,
+    maximum: Point,
+}
+
+impl BoundingBox {
+    pub fn new(minimum: Point, maximum: Point) -> Self {
+        BoundingBox { minimum, maximum }
+    }
+
+    pub fn empty() -> Self {
+        BoundingBox::new(
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        )
+    }
+
+    pub fn infinite() -> Self {
+        BoundingBox::new(
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        )
+    }
+
+    pub fn get_minimum(&self) -> &Point {
+        &self.minimum
+    }
+
+    pub fn get_maximum(&self) -> &Point {
+        &self.maximum
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..3).any(|axis| self.minimum[axis] > self.maximum[axis])
+    }
+
+    pub fn is_finite(&self) -> bool {
+        (0..3).all(|axis| self.minimum[axis].is_finite() && self.maximum[axis].is_finite())
+    }
+
+    pub fn add_point(&mut self, point: &Point) {
+        self.minimum = Point::new(
+            self.minimum.get_x().min(point.get_x()),
+            self.minimum.get_y().min(point.get_y()),
+            self.minimum.get_z().min(point.get_z()),
+        );
+        self.maximum = Point::new(
+            self.maximum.get_x().max(point.get_x()),
+            self.maximum.get_y().max(point.get_y()),
+            self.maximum.get_z().max(point.get_z()),
+        );
+    }
+
+    pub fn merge(&mut self, other: &BoundingBox) {
+        if other.is_empty() {
+            return;
+        }
+        self.add_point(&other.minimum);
+        self.add_point(&other.maximum);
+    }
+
+    pub fn get_centroid(&self) -> Point {
+        &self.minimum + &(&(&self.maximum - &self.minimum) * 0.5)
+    }
+
+    pub fn get_surface_area(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let extent = &self.maximum - &self.minimum;
+        let (x, y, z) = (extent.get_x(), extent.get_y(), extent.get_z());
+        2.0 * (x * y + y * z + z * x)
+    }
+
+    pub fn transform(&self, matrix: &SquareMatrix) -> Self {
+        if self.is_empty() {
+            return BoundingBox::empty();
+        }
+        if !self.is_finite() {
+            return BoundingBox::infinite();
+        }
+        let mut result = BoundingBox::empty();
+        for x in [self.minimum.get_x(), self.maximum.get_x()] {
+            for y in [self.minimum.get_y(), self.maximum.get_y()] {
+                for z in [self.minimum.get_z(), self.maximum.get_z()] {
+                    result.add_point(&(matrix * &Point::new(x, y, z)));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let origin = ray.get_origin();
+        let direction = ray.get_direction();
+        let mut tmin = f64::NEG_INFINITY;
+        let mut tmax = f64::INFINITY;
+        for axis in 0..3 {
+            let inverse = 1.0 / direction[axis];
+            let mut t0 = (self.minimum[axis] - origin[axis]) * inverse;
+            let mut t1 = (self.maximum[axis] - origin[axis]) * inverse;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            tmin = tmin.max(t0);
+            tmax = tmax.min(t1);
+        }
+        tmin <= tmax
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/bounding_box_tests.rs"]
+mod tests;
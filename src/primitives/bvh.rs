@@ -0,0 +1,147 @@
+use crate::objects::Hittable;
+
+use super::{BoundingBox, Hit, Point, Ray};
+
+const MAX_LEAF_SIZE: usize = 4;
+
+#[derive(Debug)]
+enum BvhNode {
+    Leaf {
+        bounds: BoundingBox,
+        objects: Vec<usize>,
+    },
+    Interior {
+        bounds: BoundingBox,
+        left: usize,
+        right: usize,
+    },
+}
+
+#[derive(Debug)]
+pub struct Bvh {
+    nodes: Vec<BvhNode>,
+    unbounded: Vec<usize>,
+}
+
+struct BuildItem {
+    index: usize,
+    bounds: BoundingBox,
+    centroid: Point,
+}
+
+impl Bvh {
+    pub fn build(objects: &[Box<dyn Hittable>]) -> Self {
+        let mut bvh = Bvh {
+            nodes: Vec::new(),
+            unbounded: Vec::new(),
+        };
+        let mut items = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            let bounds = object.get_bounds();
+            if bounds.is_empty() {
+                continue;
+            }
+            if bounds.is_finite() {
+                let centroid = bounds.get_centroid();
+                items.push(BuildItem {
+                    index,
+                    bounds,
+                    centroid,
+                });
+            } else {
+                bvh.unbounded.push(index);
+            }
+        }
+        if !items.is_empty() {
+            bvh.build_node(&mut items);
+        }
+        bvh
+    }
+
+    pub fn get_node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn get_unbounded(&self) -> &Vec<usize> {
+        &self.unbounded
+    }
+
+    pub fn get_hits<'a>(&self, objects: &'a [Box<dyn Hittable>], ray: &Ray) -> Vec<Hit<'a>> {
+        let mut result = Vec::new();
+        for index in self.unbounded.iter() {
+            result.append(&mut objects[*index].get_hits(ray));
+        }
+        if self.nodes.is_empty() {
+            return result;
+        }
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            match &self.nodes[node] {
+                BvhNode::Leaf {
+                    bounds,
+                    objects: indices,
+                } => {
+                    if bounds.intersects(ray) {
+                        for index in indices.iter() {
+                            result.append(&mut objects[*index].get_hits(ray));
+                        }
+                    }
+                }
+                BvhNode::Interior {
+                    bounds,
+                    left,
+                    right,
+                } => {
+                    if bounds.intersects(ray) {
+                        stack.push(*right);
+                        stack.push(*left);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn build_node(&mut self, items: &mut [BuildItem]) -> usize {
+        let mut bounds = BoundingBox::empty();
+        let mut centroid_bounds = BoundingBox::empty();
+        for item in items.iter() {
+            bounds.merge(&item.bounds);
+            centroid_bounds.add_point(&item.centroid);
+        }
+        let extent = centroid_bounds.get_maximum() - centroid_bounds.get_minimum();
+        let axis = if extent.get_x() >= extent.get_y() && extent.get_x() >= extent.get_z() {
+            0
+        } else if extent.get_y() >= extent.get_z() {
+            1
+        } else {
+            2
+        };
+
+        let node = self.nodes.len();
+        if items.len() <= MAX_LEAF_SIZE || extent[axis] <= 0.0 {
+            let objects = items.iter().map(|item| item.index).collect();
+            self.nodes.push(BvhNode::Leaf { bounds, objects });
+            return node;
+        }
+
+        self.nodes.push(BvhNode::Leaf {
+            bounds: BoundingBox::empty(),
+            objects: Vec::new(),
+        });
+        items.sort_unstable_by(|a, b| a.centroid[axis].total_cmp(&b.centroid[axis]));
+        let (left_items, right_items) = items.split_at_mut(items.len() / 2);
+        let left = self.build_node(left_items);
+        let right = self.build_node(right_items);
+        self.nodes[node] = BvhNode::Interior {
+            bounds,
+            left,
+            right,
+        };
+        node
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/bvh_tests.rs"]
+mod tests;
@@ -1,7 +1,10 @@
-use std::fs::OpenOptions;
-use std::io::{Result, Write};
+use std::fs::{self, File};
+use std::io::{BufWriter, Error, ErrorKind, Result, Write};
+use std::path::Path;
 
-use super::RgbColor;
+use crate::tone_mapping::ToneMapper;
+
+use super::{hdr, png, ppm, PpmError, RgbColor};
 
 pub struct Canvas {
     pixels: Vec<Vec<RgbColor>>,
@@ -16,6 +19,15 @@ impl Canvas {
         Canvas { pixels }
     }
 
+    pub fn from_ppm(filename: &str) -> std::result::Result<Self, PpmError> {
+        let contents = fs::read(filename)?;
+        Canvas::parse_ppm(&contents)
+    }
+
+    pub fn parse_ppm(contents: &[u8]) -> std::result::Result<Self, PpmError> {
+        ppm::parse(contents)
+    }
+
     pub fn get_pixel(&self, row: usize, col: usize) -> RgbColor {
         self.pixels[row][col].clone()
     }
@@ -32,54 +44,102 @@ impl Canvas {
         self.pixels.len()
     }
 
-    pub fn correct_colors(&mut self) {
-        let mut max: f64 = 0.0;
-        for row in self.pixels.iter_mut() {
-            for pixel in row.iter_mut() {
-                pixel.gamma_correct();
-                max = max.max(pixel.get_red().max(pixel.get_blue().max(pixel.get_green())));
+    pub fn to_ppm(&self, filename: &str, tone_mapper: &dyn ToneMapper) -> Result<()> {
+        self.write_ppm(BufWriter::new(File::create(filename)?), tone_mapper)
+    }
+
+    /// Writes the image in the format implied by the file extension. The tone
+    /// mapper only applies to 8-bit formats; PFM and HDR keep linear values.
+    pub fn save(&self, filename: &str, tone_mapper: &dyn ToneMapper) -> Result<()> {
+        let extension = Path::new(filename)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("ppm") => {
+                self.write_ppm_binary(BufWriter::new(File::create(filename)?), tone_mapper)
             }
+            Some("png") => self.write_png(BufWriter::new(File::create(filename)?), tone_mapper),
+            Some("pfm") => self.write_pfm(BufWriter::new(File::create(filename)?)),
+            Some("hdr") => self.write_hdr(BufWriter::new(File::create(filename)?)),
+            _ => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unsupported image extension in '{}'", filename),
+            )),
         }
-        for row in self.pixels.iter_mut() {
-            for pixel in row.iter_mut() {
-                pixel.correct_with(max);
+    }
+
+    pub fn write_ppm<W: Write>(&self, mut writer: W, tone_mapper: &dyn ToneMapper) -> Result<()> {
+        write!(
+            writer,
+            "P3\n{} {}\n255\n",
+            self.get_width(),
+            self.get_height()
+        )?;
+        for row in self.pixels.iter() {
+            for (index, pixel) in row.iter().enumerate() {
+                let (r, g, b) = tone_mapper.encode(pixel);
+                if index > 0 {
+                    writer.write_all(b" ")?;
+                }
+                write!(writer, "{} {} {}", r, g, b)?;
             }
+            writer.write_all(b"\n")?;
         }
+        writer.flush()
     }
 
-    pub fn to_ppm(&self, filename: &str) -> Result<()> {
-        let contents = self.build_contents();
-        let mut file = OpenOptions::new()
-            .write(true)
-            .truncate(true)
-            .open(filename)?;
-        file.write_all(contents.as_bytes())?;
-        Ok(())
+    pub fn write_ppm_binary<W: Write>(
+        &self,
+        mut writer: W,
+        tone_mapper: &dyn ToneMapper,
+    ) -> Result<()> {
+        write!(
+            writer,
+            "P6\n{} {}\n255\n",
+            self.get_width(),
+            self.get_height()
+        )?;
+        let mut bytes = Vec::with_capacity(self.get_width() * 3);
+        for row in self.pixels.iter() {
+            bytes.clear();
+            for pixel in row.iter() {
+                let (r, g, b) = tone_mapper.encode(pixel);
+                bytes.extend_from_slice(&[r, g, b]);
+            }
+            writer.write_all(&bytes)?;
+        }
+        writer.flush()
     }
 
-    fn build_contents(&self) -> String {
-        let mut content = "P3\n".to_string();
-        content.push_str(&self.pixels[0].len().to_string());
-        content.push(' ');
-        content.push_str(&self.pixels.len().to_string());
-        content.push_str("\n255\n");
-        for row in self.pixels.iter() {
-            let mut first = true;
-            for col in row.iter() {
-                let (r, g, b) = col.to_u8_tuple();
-                if !first {
-                    content.push(' ');
+    pub fn write_png<W: Write>(&self, writer: W, tone_mapper: &dyn ToneMapper) -> Result<()> {
+        png::write(self, writer, tone_mapper)
+    }
+
+    /// Writes the unclamped linear buffer as a little-endian PFM, whose rows
+    /// run from the bottom of the image to the top.
+    pub fn write_pfm<W: Write>(&self, mut writer: W) -> Result<()> {
+        write!(
+            writer,
+            "PF\n{} {}\n-1.0\n",
+            self.get_width(),
+            self.get_height()
+        )?;
+        let mut bytes = Vec::with_capacity(self.get_width() * 12);
+        for row in self.pixels.iter().rev() {
+            bytes.clear();
+            for pixel in row.iter() {
+                for value in [pixel.get_red(), pixel.get_green(), pixel.get_blue()] {
+                    bytes.extend_from_slice(&(value as f32).to_le_bytes());
                 }
-                first = false;
-                content.push_str(&r.to_string());
-                content.push(' ');
-                content.push_str(&g.to_string());
-                content.push(' ');
-                content.push_str(&b.to_string());
             }
-            content.push('\n');
+            writer.write_all(&bytes)?;
         }
-        content
+        writer.flush()
+    }
+
+    pub fn write_hdr<W: Write>(&self, writer: W) -> Result<()> {
+        hdr::write(self, writer)
     }
 }
 
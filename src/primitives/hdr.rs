@@ -0,0 +1,106 @@
+use std::io::{Result, Write};
+
+use super::{Canvas, RgbColor};
+
+const MIN_RUN: usize = 4;
+const MAX_RUN: usize = 127;
+const MAX_DUMP: usize = 128;
+/// The largest value an RGBE mantissa and exponent can represent.
+const MAX_VALUE: f64 = 255.0 / 256.0 * (1u128 << 127) as f64;
+
+pub(super) fn to_rgbe(color: &RgbColor) -> [u8; 4] {
+    let red = color.get_red().clamp(0.0, MAX_VALUE);
+    let green = color.get_green().clamp(0.0, MAX_VALUE);
+    let blue = color.get_blue().clamp(0.0, MAX_VALUE);
+    let max = red.max(green).max(blue);
+    if max.is_nan() || max < 1e-32 {
+        return [0, 0, 0, 0];
+    }
+    let mut exponent = max.log2().floor() as i32 + 1;
+    if max / 2f64.powi(exponent) >= 1.0 {
+        exponent += 1;
+    }
+    let exponent = exponent.clamp(-128, 127);
+    let scale = 256.0 / 2f64.powi(exponent);
+    [
+        (red * scale).min(255.0) as u8,
+        (green * scale).min(255.0) as u8,
+        (blue * scale).min(255.0) as u8,
+        (exponent + 128) as u8,
+    ]
+}
+
+fn write_channel<W: Write>(writer: &mut W, data: &[u8]) -> Result<()> {
+    let mut current = 0;
+    while current < data.len() {
+        let mut run_start = current;
+        let mut run_count = 0;
+        let mut previous_run_count = 0;
+        while run_count < MIN_RUN && run_start < data.len() {
+            run_start += run_count;
+            previous_run_count = run_count;
+            run_count = 1;
+            while run_start + run_count < data.len()
+                && run_count < MAX_RUN
+                && data[run_start] == data[run_start + run_count]
+            {
+                run_count += 1;
+            }
+        }
+        if previous_run_count > 1 && previous_run_count == run_start - current {
+            writer.write_all(&[128 + previous_run_count as u8, data[current]])?;
+            current = run_start;
+        }
+        while current < run_start {
+            let count = (run_start - current).min(MAX_DUMP);
+            writer.write_all(&[count as u8])?;
+            writer.write_all(&data[current..current + count])?;
+            current += count;
+        }
+        if run_count >= MIN_RUN {
+            writer.write_all(&[128 + run_count as u8, data[run_start]])?;
+            current += run_count;
+        }
+    }
+    Ok(())
+}
+
+/// Writes a Radiance RGBE image, run-length encoding each scanline when the
+/// width allows it.
+pub(super) fn write<W: Write>(canvas: &Canvas, mut writer: W) -> Result<()> {
+    let width = canvas.get_width();
+    let height = canvas.get_height();
+    write!(
+        writer,
+        "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {} +X {}\n",
+        height, width
+    )?;
+    let use_rle = (8..32768).contains(&width);
+    let mut channels: [Vec<u8>; 4] = Default::default();
+    for row in 0..height {
+        for channel in channels.iter_mut() {
+            channel.clear();
+        }
+        for col in 0..width {
+            let rgbe = to_rgbe(&canvas.get_pixel(row, col));
+            if use_rle {
+                for (channel, value) in channels.iter_mut().zip(rgbe) {
+                    channel.push(value);
+                }
+            } else {
+                writer.write_all(&rgbe)?;
+            }
+        }
+        if use_rle {
+            writer.write_all(&[2, 2, (width >> 8) as u8, (width & 0xff) as u8])?;
+            for channel in channels.iter() {
+                write_channel(&mut writer, channel)?;
+            }
+        }
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+#[path = "tests/hdr_tests.rs"]
+mod tests;
@@ -1,42 +1,77 @@
 use std::cmp::Ordering;
+use std::fmt;
 
-use crate::objects::Sphere;
+use crate::objects::Hittable;
 
 use super::{HitRecord, Ray};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Clone)]
 pub struct Hit<'a> {
     t: f64,
-    object: &'a Sphere,
+    object: &'a dyn Hittable,
+    u: f64,
+    v: f64,
 }
 
 impl<'a> Hit<'a> {
-    pub fn new(t: f64, object: &'a Sphere) -> Self {
-        Hit { t, object }
+    pub fn new(t: f64, object: &'a dyn Hittable) -> Self {
+        Hit::with_uv(t, object, 0.0, 0.0)
+    }
+
+    pub fn with_uv(t: f64, object: &'a dyn Hittable, u: f64, v: f64) -> Self {
+        Hit { t, object, u, v }
     }
 
     pub fn get_t(&self) -> f64 {
         self.t
     }
 
-    pub fn get_object(&self) -> &Sphere {
+    pub fn get_object(&self) -> &'a dyn Hittable {
         self.object
     }
 
+    pub fn get_u(&self) -> f64 {
+        self.u
+    }
+
+    pub fn get_v(&self) -> f64 {
+        self.v
+    }
+
     pub fn cmp_ignore_nan(&self, other: &Self) -> Ordering {
-        if self.t == f64::NAN && other.t == f64::NAN {
+        if self.t.is_nan() && other.t.is_nan() {
             Ordering::Equal
-        } else if self.t == f64::NAN {
+        } else if self.t.is_nan() {
             Ordering::Greater
-        } else if other.t == f64::NAN {
+        } else if other.t.is_nan() {
             Ordering::Less
         } else {
-            self.partial_cmp(&other).unwrap()
+            self.partial_cmp(other).unwrap()
         }
     }
 
-    pub fn get_hit_record(&self, ray: &Ray) -> HitRecord {
-        HitRecord::new(self, ray)
+    pub fn get_hit_record(&self, ray: &Ray, hits: &[Hit<'a>]) -> HitRecord<'a> {
+        HitRecord::new(self, ray, hits)
+    }
+}
+
+impl<'a> fmt::Debug for Hit<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hit")
+            .field("t", &self.t)
+            .field("object", &(self.object as *const dyn Hittable))
+            .field("u", &self.u)
+            .field("v", &self.v)
+            .finish()
+    }
+}
+
+impl<'a> PartialEq for Hit<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t
+            && self.u == other.u
+            && self.v == other.v
+            && std::ptr::addr_eq(self.object, other.object)
     }
 }
 
@@ -1,39 +1,90 @@
-use crate::objects::{Hittable, Sphere};
+use crate::objects::Hittable;
 
 use super::{Hit, Point, Ray, Vector};
 
+pub const DEFAULT_EPSILON: f64 = 1e-6;
+
 pub struct HitRecord<'a> {
     t: f64,
-    object: &'a Sphere,
+    object: &'a dyn Hittable,
     hit_point: Point,
+    over_point: Point,
+    under_point: Point,
     eye_vector: Vector,
     normal_vector: Vector,
     is_inside: bool,
     is_in_shadow: bool,
+    n1: f64,
+    n2: f64,
 }
 
 impl<'a> HitRecord<'a> {
-    pub fn new(hit: &'a Hit, ray: &Ray) -> Self {
+    pub fn new(hit: &Hit<'a>, ray: &Ray, hits: &[Hit<'a>]) -> Self {
+        HitRecord::with_epsilon(hit, ray, hits, DEFAULT_EPSILON)
+    }
+
+    pub fn with_epsilon(hit: &Hit<'a>, ray: &Ray, hits: &[Hit<'a>], epsilon: f64) -> Self {
         let t = hit.get_t();
         let hit_point = ray.at(t);
         let eye_vector = -ray.get_direction();
-        let mut normal_vector = hit.get_object().get_normal(&hit_point);
-        let is_inside = if &normal_vector * &eye_vector < 0.0 {
-            true
-        } else {
-            false
-        };
+        let mut normal_vector = hit.get_object().get_normal_at(&hit_point, hit);
+        let is_inside = &normal_vector * &eye_vector < 0.0;
         if is_inside {
             normal_vector = -&normal_vector;
         }
+        let offset = &normal_vector * epsilon;
+        let over_point = &hit_point + &offset;
+        let under_point = &hit_point - &offset;
+        let (n1, n2) = HitRecord::refractive_indices(hit, hits, is_inside);
         HitRecord {
             t,
             object: hit.get_object(),
             normal_vector,
             hit_point,
+            over_point,
+            under_point,
             eye_vector,
             is_inside,
             is_in_shadow: false,
+            n1,
+            n2,
+        }
+    }
+
+    fn refractive_indices(hit: &Hit<'a>, hits: &[Hit<'a>], is_inside: bool) -> (f64, f64) {
+        let mut containers: Vec<&dyn Hittable> = Vec::new();
+        let mut n1 = 1.0;
+        for candidate in hits.iter() {
+            let is_hit = candidate == hit;
+            if is_hit {
+                n1 = HitRecord::innermost_index(&containers);
+            }
+            let object = candidate.get_object();
+            match containers
+                .iter()
+                .position(|container| std::ptr::addr_eq(*container, object))
+            {
+                Some(index) => {
+                    containers.remove(index);
+                }
+                None => containers.push(object),
+            }
+            if is_hit {
+                return (n1, HitRecord::innermost_index(&containers));
+            }
+        }
+        let index = hit.get_object().get_material().get_refractive_index();
+        if is_inside {
+            (index, 1.0)
+        } else {
+            (1.0, index)
+        }
+    }
+
+    fn innermost_index(containers: &[&dyn Hittable]) -> f64 {
+        match containers.last() {
+            Some(object) => object.get_material().get_refractive_index(),
+            None => 1.0,
         }
     }
 
@@ -41,7 +92,7 @@ impl<'a> HitRecord<'a> {
         self.t
     }
 
-    pub fn get_object(&self) -> &'a Sphere {
+    pub fn get_object(&self) -> &'a dyn Hittable {
         self.object
     }
 
@@ -49,6 +100,14 @@ impl<'a> HitRecord<'a> {
         &self.hit_point
     }
 
+    pub fn get_over_point(&self) -> &Point {
+        &self.over_point
+    }
+
+    pub fn get_under_point(&self) -> &Point {
+        &self.under_point
+    }
+
     pub fn get_eye_vector(&self) -> &Vector {
         &self.eye_vector
     }
@@ -61,11 +120,23 @@ impl<'a> HitRecord<'a> {
         self.is_inside
     }
 
-    pub fn get_is_in_shadow(&self)->bool {
+    pub fn get_is_in_shadow(&self) -> bool {
         self.is_in_shadow
     }
 
     pub fn set_is_in_shadow(&mut self, is_in_shadow: bool) {
         self.is_in_shadow = is_in_shadow;
     }
+
+    pub fn get_n1(&self) -> f64 {
+        self.n1
+    }
+
+    pub fn get_n2(&self) -> f64 {
+        self.n2
+    }
 }
+
+#[cfg(test)]
+#[path = "tests/hit_record_tests.rs"]
+mod tests;
@@ -1,17 +1,26 @@
+mod bounding_box;
+mod bvh;
 mod canvas;
+mod hdr;
 mod hit;
 mod hit_record;
+mod png;
 mod point;
+mod ppm;
 mod ray;
 mod rgb_color;
 mod square_matrix;
 mod vector;
 mod world;
+mod zlib;
 
+pub use bounding_box::BoundingBox;
+pub use bvh::Bvh;
 pub use canvas::Canvas;
 pub use hit::Hit;
-pub use hit_record::HitRecord;
+pub use hit_record::{HitRecord, DEFAULT_EPSILON};
 pub use point::Point;
+pub use ppm::PpmError;
 pub use ray::Ray;
 pub use rgb_color::RgbColor;
 pub use square_matrix::SquareMatrix;
@@ -0,0 +1,159 @@
+use std::io::{Result, Write};
+
+use crate::tone_mapping::ToneMapper;
+
+use super::zlib::{update_crc32, ZlibEncoder};
+use super::Canvas;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const MAX_CHUNK_SIZE: usize = 1 << 15;
+const BYTES_PER_PIXEL: usize = 3;
+
+fn write_chunk<W: Write>(writer: &mut W, kind: &[u8; 4], data: &[u8]) -> Result<()> {
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+    let crc = update_crc32(update_crc32(0, kind), data);
+    writer.write_all(&crc.to_be_bytes())
+}
+
+/// Buffers compressed image data and emits it as a sequence of IDAT chunks.
+struct IdatWriter<W: Write> {
+    inner: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> IdatWriter<W> {
+    fn finish(mut self) -> Result<W> {
+        self.flush_chunk()?;
+        Ok(self.inner)
+    }
+
+    fn flush_chunk(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() {
+            write_chunk(&mut self.inner, b"IDAT", &self.buffer)?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for IdatWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= MAX_CHUNK_SIZE {
+            self.flush_chunk()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = left as i16 + up as i16 - up_left as i16;
+    let distance_left = (estimate - left as i16).abs();
+    let distance_up = (estimate - up as i16).abs();
+    let distance_up_left = (estimate - up_left as i16).abs();
+    if distance_left <= distance_up && distance_left <= distance_up_left {
+        left
+    } else if distance_up <= distance_up_left {
+        up
+    } else {
+        up_left
+    }
+}
+
+fn filter_row(filter: u8, row: &[u8], previous: &[u8], output: &mut Vec<u8>) {
+    output.clear();
+    output.push(filter);
+    for index in 0..row.len() {
+        let left = if index >= BYTES_PER_PIXEL {
+            row[index - BYTES_PER_PIXEL]
+        } else {
+            0
+        };
+        let up = previous[index];
+        let up_left = if index >= BYTES_PER_PIXEL {
+            previous[index - BYTES_PER_PIXEL]
+        } else {
+            0
+        };
+        let predicted = match filter {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => ((left as u16 + up as u16) / 2) as u8,
+            _ => paeth(left, up, up_left),
+        };
+        output.push(row[index].wrapping_sub(predicted));
+    }
+}
+
+/// Picks the filter with the smallest sum of absolute residuals per row, the
+/// heuristic recommended by the PNG specification.
+fn write_filtered_row<W: Write>(
+    writer: &mut W,
+    row: &[u8],
+    previous: &[u8],
+    candidates: &mut [Vec<u8>; 5],
+) -> Result<()> {
+    let mut best = 0;
+    let mut best_cost = u64::MAX;
+    for (filter, candidate) in candidates.iter_mut().enumerate() {
+        filter_row(filter as u8, row, previous, candidate);
+        let cost = candidate[1..]
+            .iter()
+            .map(|&byte| (byte as i8).unsigned_abs() as u64)
+            .sum();
+        if cost < best_cost {
+            best = filter;
+            best_cost = cost;
+        }
+    }
+    writer.write_all(&candidates[best])
+}
+
+pub(super) fn write<W: Write>(
+    canvas: &Canvas,
+    mut writer: W,
+    tone_mapper: &dyn ToneMapper,
+) -> Result<()> {
+    let width = canvas.get_width();
+    let height = canvas.get_height();
+    writer.write_all(&SIGNATURE)?;
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    write_chunk(&mut writer, b"IHDR", &header)?;
+
+    let idat = IdatWriter {
+        inner: &mut writer,
+        buffer: Vec::with_capacity(MAX_CHUNK_SIZE),
+    };
+    let mut encoder = ZlibEncoder::new(idat);
+    let mut previous = vec![0; width * BYTES_PER_PIXEL];
+    let mut row = Vec::with_capacity(width * BYTES_PER_PIXEL);
+    let mut candidates: [Vec<u8>; 5] = Default::default();
+    for row_index in 0..height {
+        row.clear();
+        for col in 0..width {
+            let (r, g, b) = tone_mapper.encode(&canvas.get_pixel(row_index, col));
+            row.extend_from_slice(&[r, g, b]);
+        }
+        write_filtered_row(&mut encoder, &row, &previous, &mut candidates)?;
+        std::mem::swap(&mut previous, &mut row);
+    }
+    encoder.finish()?.finish()?;
+
+    write_chunk(&mut writer, b"IEND", &[])?;
+    writer.flush()
+}
+
+#[cfg(test)]
+#[path = "tests/png_tests.rs"]
+mod tests;
@@ -0,0 +1,210 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use super::{Canvas, RgbColor};
+
+#[derive(Debug)]
+pub enum PpmError {
+    Io(io::Error),
+    Header(String),
+    Data(String),
+}
+
+impl fmt::Display for PpmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PpmError::Io(error) => write!(f, "could not read ppm file: {}", error),
+            PpmError::Header(message) => write!(f, "invalid ppm header: {}", message),
+            PpmError::Data(message) => write!(f, "invalid ppm pixel data: {}", message),
+        }
+    }
+}
+
+impl Error for PpmError {}
+
+impl From<io::Error> for PpmError {
+    fn from(error: io::Error) -> Self {
+        PpmError::Io(error)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Ascii,
+    Binary,
+}
+
+struct Reader<'a> {
+    contents: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&byte) = self.contents.get(self.position) {
+            if byte == b'#' {
+                while let Some(&byte) = self.contents.get(self.position) {
+                    if byte == b'\n' || byte == b'\r' {
+                        break;
+                    }
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> Option<&'a str> {
+        self.skip_whitespace_and_comments();
+        let start = self.position;
+        while let Some(&byte) = self.contents.get(self.position) {
+            if byte.is_ascii_whitespace() || byte == b'#' {
+                break;
+            }
+            self.position += 1;
+        }
+        if start == self.position {
+            return None;
+        }
+        std::str::from_utf8(&self.contents[start..self.position]).ok()
+    }
+
+    fn next_header_value(&mut self, name: &str) -> Result<usize, PpmError> {
+        let token = self
+            .next_token()
+            .ok_or_else(|| PpmError::Header(format!("missing {}", name)))?;
+        token
+            .parse::<usize>()
+            .map_err(|_| PpmError::Header(format!("invalid {} '{}'", name, token)))
+    }
+}
+
+pub(super) fn parse(contents: &[u8]) -> Result<Canvas, PpmError> {
+    let mut reader = Reader {
+        contents,
+        position: 0,
+    };
+    let format = match reader.next_token() {
+        Some("P3") => Format::Ascii,
+        Some("P6") => Format::Binary,
+        Some(magic) => {
+            return Err(PpmError::Header(format!(
+                "unsupported magic number '{}', expected P3 or P6",
+                magic
+            )))
+        }
+        None => return Err(PpmError::Header("missing magic number".to_string())),
+    };
+    let width = reader.next_header_value("width")?;
+    let height = reader.next_header_value("height")?;
+    let max = reader.next_header_value("max color value")?;
+    if width == 0 || height == 0 {
+        return Err(PpmError::Header(format!(
+            "image size {}x{} must be positive",
+            width, height
+        )));
+    }
+    if max == 0 || max > 65535 {
+        return Err(PpmError::Header(format!(
+            "max color value {} is outside 1..=65535",
+            max
+        )));
+    }
+
+    let count = width
+        .checked_mul(height)
+        .and_then(|pixels| pixels.checked_mul(3))
+        .ok_or_else(|| PpmError::Header(format!("image size {}x{} is too large", width, height)))?;
+    let samples = match format {
+        Format::Ascii => read_ascii_samples(&mut reader, count, max)?,
+        Format::Binary => read_binary_samples(&mut reader, count, max)?,
+    };
+    let mut canvas = Canvas::new(width, height, None);
+    let scale = max as f64;
+    for (index, pixel) in samples.chunks_exact(3).enumerate() {
+        let color = RgbColor::new(
+            pixel[0] as f64 / scale,
+            pixel[1] as f64 / scale,
+            pixel[2] as f64 / scale,
+        );
+        canvas.set_pixel(index / width, index % width, color);
+    }
+    Ok(canvas)
+}
+
+fn read_ascii_samples(
+    reader: &mut Reader<'_>,
+    count: usize,
+    max: usize,
+) -> Result<Vec<usize>, PpmError> {
+    // Every sample takes at least one byte, so the input bounds the allocation
+    // whatever the header claims.
+    let remaining = reader.contents.len() - reader.position;
+    let mut samples = Vec::with_capacity(count.min(remaining));
+    for index in 0..count {
+        let token = reader.next_token().ok_or_else(|| {
+            PpmError::Data(format!("expected {} samples, found {}", count, index))
+        })?;
+        let sample = token
+            .parse::<usize>()
+            .map_err(|_| PpmError::Data(format!("invalid sample '{}' at {}", token, index)))?;
+        if sample > max {
+            return Err(PpmError::Data(format!(
+                "sample {} at {} exceeds max color value {}",
+                sample, index, max
+            )));
+        }
+        samples.push(sample);
+    }
+    Ok(samples)
+}
+
+fn read_binary_samples(
+    reader: &mut Reader<'_>,
+    count: usize,
+    max: usize,
+) -> Result<Vec<usize>, PpmError> {
+    match reader.contents.get(reader.position) {
+        Some(byte) if byte.is_ascii_whitespace() => reader.position += 1,
+        _ => {
+            return Err(PpmError::Header(
+                "expected a single whitespace byte after max color value".to_string(),
+            ))
+        }
+    }
+    let bytes_per_sample = if max < 256 { 1 } else { 2 };
+    let raster = &reader.contents[reader.position..];
+    let expected = count.checked_mul(bytes_per_sample);
+    if expected.is_none_or(|expected| raster.len() < expected) {
+        return Err(PpmError::Data(format!(
+            "expected {} samples of {} bytes, found {} bytes",
+            count,
+            bytes_per_sample,
+            raster.len()
+        )));
+    }
+    let samples: Vec<usize> = raster
+        .chunks_exact(bytes_per_sample)
+        .take(count)
+        .map(|bytes| {
+            bytes
+                .iter()
+                .fold(0, |sample, &byte| (sample << 8) | byte as usize)
+        })
+        .collect();
+    if let Some(index) = samples.iter().position(|&sample| sample > max) {
+        return Err(PpmError::Data(format!(
+            "sample {} at {} exceeds max color value {}",
+            samples[index], index, max
+        )));
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+#[path = "tests/ppm_tests.rs"]
+mod tests;
@@ -167,11 +167,10 @@ impl Mul<&Point> for &SquareMatrix {
     type Output = Point;
 
     fn mul(self, rhs: &Point) -> Self::Output {
-        let len = self.elements.len();
         let mut data = [0.0; 4];
-        for row in 0..len {
-            for col in 0..len {
-                data[row] += self.elements[row][col] * rhs[col];
+        for (value, row) in data.iter_mut().zip(self.elements.iter()) {
+            for (col, element) in row.iter().enumerate() {
+                *value += element * rhs[col];
             }
         }
         Point::new(data[0], data[1], data[2])
@@ -182,11 +181,10 @@ impl Mul<&Vector> for &SquareMatrix {
     type Output = Vector;
 
     fn mul(self, rhs: &Vector) -> Self::Output {
-        let len = self.elements.len();
         let mut data = [0.0; 4];
-        for row in 0..len {
-            for col in 0..len {
-                data[row] += self.elements[row][col] * rhs[col];
+        for (value, row) in data.iter_mut().zip(self.elements.iter()) {
+            for (col, element) in row.iter().enumerate() {
+                *value += element * rhs[col];
             }
         }
         Vector::new(data[0], data[1], data[2])
@@ -0,0 +1,113 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+use crate::primitives::{Point, Ray, SquareMatrix, Vector};
+
+use super::BoundingBox;
+
+#[test]
+fn empty_box_is_empty_and_merges_as_identity() {
+    let mut bounds = BoundingBox::empty();
+    assert!(bounds.is_empty());
+
+    bounds.merge(&BoundingBox::new(
+        Point::new(-1., -2., -3.),
+        Point::new(3., 2., 1.),
+    ));
+
+    assert!(!bounds.is_empty());
+    assert_eq!(bounds.get_minimum(), &Point::new(-1., -2., -3.));
+    assert_eq!(bounds.get_maximum(), &Point::new(3., 2., 1.));
+}
+
+#[test]
+fn add_point_grows_box() {
+    let mut bounds = BoundingBox::empty();
+
+    bounds.add_point(&Point::new(-5., 2., 0.));
+    bounds.add_point(&Point::new(7., 0., -3.));
+
+    assert_eq!(bounds.get_minimum(), &Point::new(-5., 0., -3.));
+    assert_eq!(bounds.get_maximum(), &Point::new(7., 2., 0.));
+}
+
+#[test]
+fn get_centroid_and_surface_area_compute_correctly() {
+    let bounds = BoundingBox::new(Point::new(-1., 0., 2.), Point::new(1., 4., 3.));
+
+    assert_eq!(bounds.get_centroid(), Point::new(0., 2., 2.5));
+    assert_eq!(
+        bounds.get_surface_area(),
+        2. * (2. * 4. + 4. * 1. + 1. * 2.)
+    );
+}
+
+#[test]
+fn transform_encloses_rotated_corners() {
+    let bounds = BoundingBox::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.));
+    let matrix = &SquareMatrix::rotation_x(PI / 4.) * &SquareMatrix::rotation_y(PI / 4.);
+
+    let transformed = bounds.transform(&matrix);
+
+    assert_eq!(
+        transformed.get_minimum(),
+        &Point::new(-SQRT_2, -1. - FRAC_1_SQRT_2, -1. - FRAC_1_SQRT_2)
+    );
+    assert_eq!(
+        transformed.get_maximum(),
+        &Point::new(SQRT_2, 1. + FRAC_1_SQRT_2, 1. + FRAC_1_SQRT_2)
+    );
+}
+
+#[test]
+fn transform_of_infinite_box_stays_infinite() {
+    let bounds = BoundingBox::new(
+        Point::new(f64::NEG_INFINITY, 0., f64::NEG_INFINITY),
+        Point::new(f64::INFINITY, 0., f64::INFINITY),
+    );
+
+    let transformed = bounds.transform(&SquareMatrix::rotation_x(PI / 2.));
+
+    assert!(!transformed.is_finite());
+    assert!(transformed.intersects(&Ray::new(Point::new(0., 0., 0.), Vector::new(1., 0., 0.))));
+}
+
+#[test]
+fn intersects_detects_hits_and_misses() {
+    let bounds = BoundingBox::new(Point::new(5., -2., 0.), Point::new(11., 4., 7.));
+    let cases = [
+        (Point::new(15., 1., 2.), Vector::new(-1., 0., 0.), true),
+        (Point::new(-5., -1., 4.), Vector::new(1., 0., 0.), true),
+        (Point::new(7., 6., 5.), Vector::new(0., -1., 0.), true),
+        (Point::new(9., -5., 6.), Vector::new(0., 1., 0.), true),
+        (Point::new(8., 2., 12.), Vector::new(0., 0., -1.), true),
+        (Point::new(6., 0., -5.), Vector::new(0., 0., 1.), true),
+        (Point::new(8., 1., 3.5), Vector::new(0., 0., 1.), true),
+        (Point::new(9., -1., -8.), Vector::new(2., 4., 6.), false),
+        (Point::new(8., 3., -4.), Vector::new(6., 2., 4.), false),
+        (Point::new(9., -1., -2.), Vector::new(4., 6., 2.), false),
+        (Point::new(4., 0., 9.), Vector::new(0., 0., -1.), false),
+        (Point::new(8., 6., -1.), Vector::new(0., -1., 0.), false),
+        (Point::new(12., 5., 4.), Vector::new(-1., 0., 0.), false),
+    ];
+
+    for (origin, direction, expected) in cases {
+        let ray = Ray::new(origin, direction.get_normal());
+
+        assert_eq!(expected, bounds.intersects(&ray));
+    }
+}
+
+#[test]
+fn intersects_includes_hits_behind_origin() {
+    let bounds = BoundingBox::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.));
+    let ray = Ray::new(Point::new(0., 0., 5.), Vector::new(0., 0., 1.));
+
+    assert!(bounds.intersects(&ray));
+}
+
+#[test]
+fn empty_box_never_intersects() {
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+
+    assert!(!BoundingBox::empty().intersects(&ray));
+}
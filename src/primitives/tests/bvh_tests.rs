@@ -0,0 +1,61 @@
+use crate::{
+    objects::{Hittable, Plane, Sphere},
+    primitives::{Point, Ray, SquareMatrix, Vector},
+};
+
+use super::Bvh;
+
+fn spheres_on_a_line(count: usize) -> Vec<Box<dyn Hittable>> {
+    (0..count)
+        .map(|index| {
+            Box::new(Sphere::with_transform(SquareMatrix::translation(
+                index as f64 * 3.,
+                0.,
+                0.,
+            ))) as Box<dyn Hittable>
+        })
+        .collect()
+}
+
+#[test]
+fn build_on_empty_list_has_no_nodes() {
+    let bvh = Bvh::build(&[]);
+
+    assert_eq!(0, bvh.get_node_count());
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    assert!(bvh.get_hits(&[], &ray).is_empty());
+}
+
+#[test]
+fn build_splits_large_lists() {
+    let objects = spheres_on_a_line(16);
+
+    let bvh = Bvh::build(&objects);
+
+    assert!(bvh.get_node_count() > 1);
+    assert!(bvh.get_unbounded().is_empty());
+}
+
+#[test]
+fn build_keeps_unbounded_objects_outside_tree() {
+    let mut objects = spheres_on_a_line(3);
+    objects.push(Box::new(Plane::identity()));
+
+    let bvh = Bvh::build(&objects);
+
+    assert_eq!(&vec![3], bvh.get_unbounded());
+}
+
+#[test]
+fn get_hits_only_reports_objects_along_ray() {
+    let objects = spheres_on_a_line(16);
+    let bvh = Bvh::build(&objects);
+    let ray = Ray::new(Point::new(30., 0., -5.), Vector::new(0., 0., 1.));
+
+    let hits = bvh.get_hits(&objects, &ray);
+
+    assert_eq!(2, hits.len());
+    for hit in hits.iter() {
+        assert!(std::ptr::addr_eq(hit.get_object(), objects[10].as_ref()));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::primitives::RgbColor;
+use crate::{primitives::RgbColor, tone_mapping::ClampToneMapper};
 
 use super::Canvas;
 
@@ -36,4 +36,116 @@ fn set_pixel_updates_correctly() {
     canvas.set_pixel(5,5,color.clone());
 
     assert_eq!(color, canvas.get_pixel(5,5));
-}
\ No newline at end of file
+}
+
+#[test]
+fn parse_ppm_reads_p3_pixels() {
+    let contents = "P3\n2 2\n255\n255 0 0 0 255 0\n0 0 255 255 255 255\n";
+
+    let canvas = Canvas::parse_ppm(contents.as_bytes()).unwrap();
+
+    assert_eq!(2, canvas.get_width());
+    assert_eq!(2, canvas.get_height());
+    assert_eq!(RgbColor::new(1., 0., 0.), canvas.get_pixel(0, 0));
+    assert_eq!(RgbColor::new(0., 1., 0.), canvas.get_pixel(0, 1));
+    assert_eq!(RgbColor::new(0., 0., 1.), canvas.get_pixel(1, 0));
+    assert_eq!(RgbColor::new(1., 1., 1.), canvas.get_pixel(1, 1));
+}
+
+#[test]
+fn parse_ppm_round_trips_written_contents() {
+    let mut canvas = Canvas::new(3, 2, None);
+    canvas.set_pixel(1, 2, RgbColor::new(1., 0., 1.));
+
+    let mut contents = Vec::new();
+    canvas
+        .write_ppm(&mut contents, &ClampToneMapper::new())
+        .unwrap();
+    let parsed = Canvas::parse_ppm(&contents).unwrap();
+
+    assert_eq!(RgbColor::new(1., 0., 1.), parsed.get_pixel(1, 2));
+    assert_eq!(RgbColor::new(0., 0., 0.), parsed.get_pixel(0, 0));
+}
+
+#[test]
+fn parse_ppm_rejects_truncated_data() {
+    assert!(Canvas::parse_ppm(b"P3\n2 2\n255\n255 0 0").is_err());
+    assert!(Canvas::parse_ppm(b"P2\n1 1\n255\n0").is_err());
+}
+
+#[test]
+fn write_ppm_streams_ascii_rows() {
+    let mut canvas = Canvas::new(2, 2, None);
+    canvas.set_pixel(0, 1, RgbColor::new(1., 0.5, 0.));
+    let mut contents = Vec::new();
+
+    canvas
+        .write_ppm(&mut contents, &ClampToneMapper::new())
+        .unwrap();
+
+    assert_eq!(
+        "P3\n2 2\n255\n0 0 0 255 188 0\n0 0 0 0 0 0\n",
+        String::from_utf8(contents).unwrap()
+    );
+}
+
+#[test]
+fn write_ppm_binary_round_trips() {
+    let mut canvas = Canvas::new(3, 2, None);
+    canvas.set_pixel(0, 0, RgbColor::new(1., 0., 1.));
+    canvas.set_pixel(1, 2, RgbColor::new(0., 1., 0.));
+    let mut contents = Vec::new();
+
+    canvas
+        .write_ppm_binary(&mut contents, &ClampToneMapper::new())
+        .unwrap();
+
+    assert!(contents.starts_with(b"P6\n3 2\n255\n"));
+    assert_eq!(b"P6\n3 2\n255\n".len() + 3 * 2 * 3, contents.len());
+    let parsed = Canvas::parse_ppm(&contents).unwrap();
+    assert_eq!(RgbColor::new(1., 0., 1.), parsed.get_pixel(0, 0));
+    assert_eq!(RgbColor::new(0., 1., 0.), parsed.get_pixel(1, 2));
+}
+
+#[test]
+fn save_picks_format_from_extension() {
+    let canvas = Canvas::new(2, 1, Some(RgbColor::new(1., 0., 0.)));
+    let directory = std::env::temp_dir();
+    let ppm = directory.join(format!("canvas_save_{}.PPM", std::process::id()));
+    let png = directory.join(format!("canvas_save_{}.png", std::process::id()));
+
+    canvas
+        .save(ppm.to_str().unwrap(), &ClampToneMapper::new())
+        .unwrap();
+    canvas
+        .save(png.to_str().unwrap(), &ClampToneMapper::new())
+        .unwrap();
+    let ppm_contents = std::fs::read(&ppm).unwrap();
+    let png_contents = std::fs::read(&png).unwrap();
+    std::fs::remove_file(&ppm).unwrap();
+    std::fs::remove_file(&png).unwrap();
+
+    assert!(ppm_contents.starts_with(b"P6\n"));
+    assert!(png_contents.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert!(canvas.save("output.bmp", &ClampToneMapper::new()).is_err());
+}
+
+#[test]
+fn write_pfm_keeps_unclamped_values_bottom_row_first() {
+    let mut canvas = Canvas::new(2, 2, None);
+    canvas.set_pixel(0, 0, RgbColor::new(12.5, 0., 1.));
+    canvas.set_pixel(1, 1, RgbColor::new(-0.25, 3., 0.5));
+    let mut contents = Vec::new();
+
+    canvas.write_pfm(&mut contents).unwrap();
+
+    let header = b"PF\n2 2\n-1.0\n";
+    assert!(contents.starts_with(header));
+    let values: Vec<f32> = contents[header.len()..]
+        .chunks(4)
+        .map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect();
+    assert_eq!(12, values.len());
+    assert_eq!(&[0., 0., 0., -0.25, 3., 0.5], &values[..6]);
+    assert_eq!(&[12.5, 0., 1., 0., 0., 0.], &values[6..]);
+}
@@ -0,0 +1,179 @@
+use crate::primitives::{Canvas, RgbColor};
+
+use super::{to_rgbe, write_channel};
+
+fn from_rgbe(rgbe: &[u8]) -> RgbColor {
+    if rgbe[3] == 0 {
+        return RgbColor::new(0., 0., 0.);
+    }
+    let scale = 2f64.powi(rgbe[3] as i32 - 136);
+    RgbColor::new(
+        (rgbe[0] as f64 + 0.5) * scale,
+        (rgbe[1] as f64 + 0.5) * scale,
+        (rgbe[2] as f64 + 0.5) * scale,
+    )
+}
+
+fn decode_channel(data: &[u8], length: usize) -> (Vec<u8>, usize) {
+    let mut output = Vec::new();
+    let mut position = 0;
+    while output.len() < length {
+        let count = data[position] as usize;
+        if count > 128 {
+            output.extend(std::iter::repeat_n(data[position + 1], count - 128));
+            position += 2;
+        } else {
+            output.extend_from_slice(&data[position + 1..position + 1 + count]);
+            position += 1 + count;
+        }
+    }
+    assert_eq!(length, output.len());
+    (output, position)
+}
+
+fn decode(contents: &[u8]) -> Vec<Vec<[u8; 4]>> {
+    let header_end = contents
+        .windows(2)
+        .position(|window| window == b"\n\n")
+        .unwrap();
+    let header = std::str::from_utf8(&contents[..header_end]).unwrap();
+    assert!(header.starts_with("#?RADIANCE\n"));
+    assert!(header.contains("FORMAT=32-bit_rle_rgbe"));
+    let rest = &contents[header_end + 2..];
+    let line_end = rest.iter().position(|&byte| byte == b'\n').unwrap();
+    let resolution: Vec<&str> = std::str::from_utf8(&rest[..line_end])
+        .unwrap()
+        .split(' ')
+        .collect();
+    assert_eq!("-Y", resolution[0]);
+    assert_eq!("+X", resolution[2]);
+    let height: usize = resolution[1].parse().unwrap();
+    let width: usize = resolution[3].parse().unwrap();
+
+    let mut data = &rest[line_end + 1..];
+    let mut rows = Vec::new();
+    for _ in 0..height {
+        if data[0] == 2 && data[1] == 2 {
+            assert_eq!(width, (data[2] as usize) << 8 | data[3] as usize);
+            data = &data[4..];
+            let mut channels = Vec::new();
+            for _ in 0..4 {
+                let (channel, used) = decode_channel(data, width);
+                channels.push(channel);
+                data = &data[used..];
+            }
+            rows.push(
+                (0..width)
+                    .map(|col| {
+                        [
+                            channels[0][col],
+                            channels[1][col],
+                            channels[2][col],
+                            channels[3][col],
+                        ]
+                    })
+                    .collect(),
+            );
+        } else {
+            rows.push(
+                data[..width * 4]
+                    .chunks(4)
+                    .map(|rgbe| rgbe.try_into().unwrap())
+                    .collect(),
+            );
+            data = &data[width * 4..];
+        }
+    }
+    assert!(data.is_empty());
+    rows
+}
+
+fn assert_close(expected: &RgbColor, actual: &RgbColor) {
+    let tolerance = expected
+        .get_red()
+        .max(expected.get_green())
+        .max(expected.get_blue())
+        / 128.;
+    assert!((expected.get_red() - actual.get_red()).abs() <= tolerance);
+    assert!((expected.get_green() - actual.get_green()).abs() <= tolerance);
+    assert!((expected.get_blue() - actual.get_blue()).abs() <= tolerance);
+}
+
+#[test]
+fn to_rgbe_matches_reference_encoding() {
+    assert_eq!([128, 128, 128, 129], to_rgbe(&RgbColor::new(1., 1., 1.)));
+    assert_eq!([128, 64, 0, 128], to_rgbe(&RgbColor::new(0.5, 0.25, 0.)));
+    assert_eq!([0, 0, 0, 0], to_rgbe(&RgbColor::new(0., 0., 0.)));
+    assert_eq!([0, 0, 0, 0], to_rgbe(&RgbColor::new(-1., -2., 0.)));
+}
+
+#[test]
+fn to_rgbe_keeps_values_above_one() {
+    let color = RgbColor::new(37.5, 2., 0.01);
+
+    assert_close(&color, &from_rgbe(&to_rgbe(&color)));
+}
+
+#[test]
+fn to_rgbe_clamps_infinite_values_to_largest_encoding() {
+    assert_eq!(
+        [255, 0, 0, 255],
+        to_rgbe(&RgbColor::new(f64::INFINITY, 0., f64::NEG_INFINITY))
+    );
+    assert_eq!(
+        to_rgbe(&RgbColor::new(f64::INFINITY, 1., 1.)),
+        to_rgbe(&RgbColor::new(f64::MAX, 1., 1.))
+    );
+}
+
+#[test]
+fn write_channel_mixes_runs_and_literals() {
+    let data = [1, 2, 3, 7, 7, 7, 7, 7, 4, 4, 5];
+    let mut encoded = Vec::new();
+
+    write_channel(&mut encoded, &data).unwrap();
+
+    assert_eq!(vec![3, 1, 2, 3, 133, 7, 3, 4, 4, 5], encoded);
+    assert_eq!(data.to_vec(), decode_channel(&encoded, data.len()).0);
+}
+
+#[test]
+fn write_channel_splits_long_runs() {
+    let data = [9; 300];
+    let mut encoded = Vec::new();
+
+    write_channel(&mut encoded, &data).unwrap();
+
+    assert_eq!(data.to_vec(), decode_channel(&encoded, data.len()).0);
+    assert!(encoded.len() <= 6);
+}
+
+#[test]
+fn write_hdr_round_trips_run_length_encoded_rows() {
+    let mut canvas = Canvas::new(20, 3, Some(RgbColor::new(4., 4., 4.)));
+    canvas.set_pixel(1, 5, RgbColor::new(100., 0.5, 0.));
+    canvas.set_pixel(2, 19, RgbColor::new(0.001, 0.002, 0.003));
+    let mut contents = Vec::new();
+
+    canvas.write_hdr(&mut contents).unwrap();
+
+    let rows = decode(&contents);
+    assert_eq!(3, rows.len());
+    for (row, pixels) in rows.iter().enumerate() {
+        for (col, rgbe) in pixels.iter().enumerate() {
+            assert_close(&canvas.get_pixel(row, col), &from_rgbe(rgbe));
+        }
+    }
+}
+
+#[test]
+fn write_hdr_uses_flat_rows_for_narrow_images() {
+    let canvas = Canvas::new(4, 2, Some(RgbColor::new(1., 1., 1.)));
+    let mut contents = Vec::new();
+
+    canvas.write_hdr(&mut contents).unwrap();
+
+    let header = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 2 +X 4\n";
+    assert_eq!(header.len() + 4 * 2 * 4, contents.len());
+    assert_eq!(vec![[128, 128, 128, 129]; 4], decode(&contents)[1]);
+}
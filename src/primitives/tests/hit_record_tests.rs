@@ -0,0 +1,118 @@
+use crate::{
+    objects::{Hittable, Sphere},
+    primitives::{Point, Ray, SquareMatrix, Vector, World},
+};
+
+use super::{HitRecord, DEFAULT_EPSILON};
+
+fn glass_sphere(transform: SquareMatrix, refractive_index: f64) -> Sphere {
+    let mut sphere = Sphere::with_transform(transform);
+    let mut material = sphere.get_material().clone();
+    material.set_transparency(1.0);
+    material.set_refractive_index(refractive_index);
+    sphere.set_material(material);
+    sphere
+}
+
+#[test]
+fn new_computes_hit_geometry() {
+    let sphere = Sphere::identity();
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+
+    let record = hits[0].get_hit_record(&ray, &hits);
+
+    assert_eq!(4.0, record.get_t());
+    assert_eq!(&Point::new(0., 0., -1.), record.get_hit_point());
+    assert_eq!(&Vector::new(0., 0., -1.), record.get_eye_vector());
+    assert_eq!(&Vector::new(0., 0., -1.), record.get_normal());
+    assert!(!record.get_is_inside());
+}
+
+#[test]
+fn new_flips_normal_when_inside() {
+    let sphere = Sphere::identity();
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+
+    let record = hits[1].get_hit_record(&ray, &hits);
+
+    assert!(record.get_is_inside());
+    assert_eq!(&Vector::new(0., 0., -1.), record.get_normal());
+}
+
+#[test]
+fn new_finds_refractive_indices_of_overlapping_spheres() {
+    let mut world = World::new();
+    world.add_object(glass_sphere(SquareMatrix::scaling(2., 2., 2.), 1.5));
+    world.add_object(glass_sphere(SquareMatrix::translation(0., 0., -0.25), 2.0));
+    world.add_object(glass_sphere(SquareMatrix::translation(0., 0., 0.25), 2.5));
+    let ray = Ray::new(Point::new(0., 0., -4.), Vector::new(0., 0., 1.));
+    let hits = world.get_hits(&ray);
+    let expected = [
+        (1.0, 1.5),
+        (1.5, 2.0),
+        (2.0, 2.5),
+        (2.5, 2.5),
+        (2.5, 1.5),
+        (1.5, 1.0),
+    ];
+
+    assert_eq!(expected.len(), hits.len());
+    for (hit, (n1, n2)) in hits.iter().zip(expected) {
+        let record = hit.get_hit_record(&ray, &hits);
+
+        assert_eq!(n1, record.get_n1());
+        assert_eq!(n2, record.get_n2());
+    }
+}
+
+#[test]
+fn new_uses_air_outside_of_every_object() {
+    let sphere = glass_sphere(SquareMatrix::identity(4), 1.5);
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+
+    let entering = hits[0].get_hit_record(&ray, &hits);
+    let exiting = hits[1].get_hit_record(&ray, &hits);
+
+    assert_eq!((1.0, 1.5), (entering.get_n1(), entering.get_n2()));
+    assert_eq!((1.5, 1.0), (exiting.get_n1(), exiting.get_n2()));
+}
+
+#[test]
+fn new_falls_back_to_inside_flag_when_hit_is_not_listed() {
+    let sphere = glass_sphere(SquareMatrix::identity(4), 1.5);
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+
+    let record = hits[1].get_hit_record(&ray, &[]);
+
+    assert_eq!((1.5, 1.0), (record.get_n1(), record.get_n2()));
+}
+
+#[test]
+fn new_offsets_over_and_under_points_along_normal() {
+    let sphere = Sphere::with_transform(SquareMatrix::translation(0., 0., 1.));
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+
+    let record = hits[0].get_hit_record(&ray, &hits);
+
+    assert!(record.get_over_point().get_z() < -DEFAULT_EPSILON / 2.);
+    assert!(record.get_hit_point().get_z() > record.get_over_point().get_z());
+    assert!(record.get_under_point().get_z() > DEFAULT_EPSILON / 2.);
+    assert!(record.get_hit_point().get_z() < record.get_under_point().get_z());
+}
+
+#[test]
+fn with_epsilon_uses_given_offset() {
+    let sphere = Sphere::identity();
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+
+    let record = HitRecord::with_epsilon(&hits[0], &ray, &hits, 0.25);
+
+    assert_eq!(&Point::new(0., 0., -1.25), record.get_over_point());
+    assert_eq!(&Point::new(0., 0., -0.75), record.get_under_point());
+}
@@ -0,0 +1,130 @@
+use crate::{
+    primitives::{zlib::tests::inflate, zlib::update_crc32, Canvas, RgbColor},
+    tone_mapping::{ClampToneMapper, ToneMapper},
+};
+
+use super::{paeth, SIGNATURE};
+
+struct Chunk {
+    kind: [u8; 4],
+    data: Vec<u8>,
+}
+
+fn read_chunks(contents: &[u8]) -> Vec<Chunk> {
+    assert_eq!(&SIGNATURE, &contents[..8]);
+    let mut chunks = Vec::new();
+    let mut position = 8;
+    while position < contents.len() {
+        let length = u32::from_be_bytes(contents[position..position + 4].try_into().unwrap());
+        let kind: [u8; 4] = contents[position + 4..position + 8].try_into().unwrap();
+        let data = contents[position + 8..position + 8 + length as usize].to_vec();
+        let crc_start = position + 8 + length as usize;
+        let crc = u32::from_be_bytes(contents[crc_start..crc_start + 4].try_into().unwrap());
+        assert_eq!(update_crc32(update_crc32(0, &kind), &data), crc);
+        chunks.push(Chunk { kind, data });
+        position = crc_start + 4;
+    }
+    chunks
+}
+
+fn decode(contents: &[u8]) -> (usize, usize, Vec<Vec<u8>>) {
+    let chunks = read_chunks(contents);
+    assert_eq!(b"IHDR", &chunks[0].kind);
+    assert_eq!(b"IEND", &chunks.last().unwrap().kind);
+    let header = &chunks[0].data;
+    let width = u32::from_be_bytes(header[0..4].try_into().unwrap()) as usize;
+    let height = u32::from_be_bytes(header[4..8].try_into().unwrap()) as usize;
+    assert_eq!(&[8, 2, 0, 0, 0], &header[8..]);
+
+    let compressed: Vec<u8> = chunks
+        .iter()
+        .filter(|chunk| &chunk.kind == b"IDAT")
+        .flat_map(|chunk| chunk.data.clone())
+        .collect();
+    let filtered = inflate(&compressed);
+    let stride = width * 3;
+    assert_eq!(height * (stride + 1), filtered.len());
+
+    let mut rows: Vec<Vec<u8>> = Vec::new();
+    for line in filtered.chunks(stride + 1) {
+        let previous = rows.last().cloned().unwrap_or(vec![0; stride]);
+        let mut row: Vec<u8> = Vec::with_capacity(stride);
+        for index in 0..stride {
+            let left = if index >= 3 { row[index - 3] } else { 0 };
+            let up = previous[index];
+            let up_left = if index >= 3 { previous[index - 3] } else { 0 };
+            let predicted = match line[0] {
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => paeth(left, up, up_left),
+                filter => panic!("unexpected filter {}", filter),
+            };
+            row.push(line[index + 1].wrapping_add(predicted));
+        }
+        rows.push(row);
+    }
+    (width, height, rows)
+}
+
+#[test]
+fn paeth_prefers_left_then_up() {
+    assert_eq!(10, paeth(10, 10, 10));
+    assert_eq!(20, paeth(10, 20, 10));
+    assert_eq!(10, paeth(10, 20, 20));
+    assert_eq!(10, paeth(30, 10, 50));
+    assert_eq!(50, paeth(40, 60, 50));
+}
+
+#[test]
+fn write_png_round_trips_pixels() {
+    let mut canvas = Canvas::new(5, 4, None);
+    for row in 0..4 {
+        for col in 0..5 {
+            let color = RgbColor::new(col as f64 / 4., row as f64 / 3., 0.5);
+            canvas.set_pixel(row, col, color);
+        }
+    }
+    let mut contents = Vec::new();
+
+    canvas
+        .write_png(&mut contents, &ClampToneMapper::new())
+        .unwrap();
+
+    let (width, height, rows) = decode(&contents);
+    assert_eq!((5, 4), (width, height));
+    for (row, bytes) in rows.iter().enumerate() {
+        for col in 0..5 {
+            let (r, g, b) = ClampToneMapper::new().encode(&canvas.get_pixel(row, col));
+            assert_eq!(&[r, g, b], &bytes[col * 3..col * 3 + 3]);
+        }
+    }
+}
+
+#[test]
+fn write_png_splits_large_images_into_several_idat_chunks() {
+    let mut canvas = Canvas::new(300, 200, None);
+    let mut state = 1u32;
+    for row in 0..200 {
+        for col in 0..300 {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let value = (state >> 24) as f64 / 255.;
+            canvas.set_pixel(row, col, RgbColor::new(value, 1. - value, 0.));
+        }
+    }
+    let mut contents = Vec::new();
+
+    canvas
+        .write_png(&mut contents, &ClampToneMapper::new())
+        .unwrap();
+
+    let idat_count = read_chunks(&contents)
+        .iter()
+        .filter(|chunk| &chunk.kind == b"IDAT")
+        .count();
+    assert!(idat_count > 1);
+    let (_, _, rows) = decode(&contents);
+    let (r, g, b) = ClampToneMapper::new().encode(&canvas.get_pixel(123, 45));
+    assert_eq!(&[r, g, b], &rows[123][45 * 3..45 * 3 + 3]);
+}
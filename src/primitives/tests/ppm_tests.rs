@@ -0,0 +1,124 @@
+use crate::primitives::RgbColor;
+
+use super::{parse, PpmError};
+
+fn assert_header_error(contents: &[u8], expected: &str) {
+    match parse(contents) {
+        Err(PpmError::Header(message)) => assert!(message.contains(expected), "{message}"),
+        other => panic!("expected header error, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn parse_skips_comments_in_header_and_data() {
+    let contents = b"P3 # magic\n# a comment line\n2 1\n# max\n255\n255 0 0 # first\n0 0 255\n";
+
+    let canvas = parse(contents).unwrap();
+
+    assert_eq!(2, canvas.get_width());
+    assert_eq!(1, canvas.get_height());
+    assert_eq!(RgbColor::new(1., 0., 0.), canvas.get_pixel(0, 0));
+    assert_eq!(RgbColor::new(0., 0., 1.), canvas.get_pixel(0, 1));
+}
+
+#[test]
+fn parse_scales_by_max_color_value() {
+    let canvas = parse(b"P3\n1 1\n100\n100 50 0\n").unwrap();
+
+    assert_eq!(RgbColor::new(1., 0.5, 0.), canvas.get_pixel(0, 0));
+}
+
+#[test]
+fn parse_reads_binary_p6() {
+    let mut contents = b"P6\n# binary\n2 1\n255\n".to_vec();
+    contents.extend_from_slice(&[255, 0, 51, 0, 255, 0]);
+
+    let canvas = parse(&contents).unwrap();
+
+    assert_eq!(RgbColor::new(1., 0., 0.2), canvas.get_pixel(0, 0));
+    assert_eq!(RgbColor::new(0., 1., 0.), canvas.get_pixel(0, 1));
+}
+
+#[test]
+fn parse_reads_binary_p6_whose_data_starts_with_whitespace_bytes() {
+    let mut contents = b"P6 1 1 255\n".to_vec();
+    contents.extend_from_slice(&[b'\n', b' ', 255]);
+
+    let canvas = parse(&contents).unwrap();
+
+    assert_eq!(
+        RgbColor::new(10. / 255., 32. / 255., 1.),
+        canvas.get_pixel(0, 0)
+    );
+}
+
+#[test]
+fn parse_reads_sixteen_bit_binary_p6() {
+    let mut contents = b"P6\n1 1\n65535\n".to_vec();
+    contents.extend_from_slice(&[0xff, 0xff, 0x00, 0x00, 0x80, 0x00]);
+
+    let canvas = parse(&contents).unwrap();
+
+    assert_eq!(
+        RgbColor::new(1., 0., 32768. / 65535.),
+        canvas.get_pixel(0, 0)
+    );
+}
+
+#[test]
+fn parse_reports_malformed_headers() {
+    assert_header_error(b"", "missing magic number");
+    assert_header_error(b"P5\n1 1\n255\n", "unsupported magic number 'P5'");
+    assert_header_error(b"P3\n", "missing width");
+    assert_header_error(b"P3\n2 x\n255\n", "invalid height 'x'");
+    assert_header_error(b"P3\n0 2\n255\n", "0x2");
+    assert_header_error(b"P3\n1 1\n0\n", "max color value 0");
+    assert_header_error(b"P3\n1 1\n70000\n", "max color value 70000");
+}
+
+#[test]
+fn parse_reports_bad_pixel_data() {
+    assert!(matches!(
+        parse(b"P3\n2 1\n255\n255 0 0\n"),
+        Err(PpmError::Data(_))
+    ));
+    assert!(matches!(
+        parse(b"P3\n1 1\n255\n256 0 0\n"),
+        Err(PpmError::Data(_))
+    ));
+    assert!(matches!(
+        parse(b"P6\n2 1\n255\n\x00\x00\x00"),
+        Err(PpmError::Data(_))
+    ));
+}
+
+#[test]
+fn error_messages_describe_the_problem() {
+    let error = parse(b"P3\n1 1\n255\n1 2\n").err().unwrap();
+
+    assert_eq!(
+        "invalid ppm pixel data: expected 3 samples, found 2",
+        error.to_string()
+    );
+}
+
+#[test]
+fn parse_rejects_sizes_that_overflow() {
+    assert_header_error(
+        b"P6\n18446744073709551615 3 255\n\x00\x00\x00",
+        "18446744073709551615x3 is too large",
+    );
+
+    let wide = format!("P6\n{} 1\n65535\n\x00\x00", usize::MAX / 3);
+    assert!(matches!(parse(wide.as_bytes()), Err(PpmError::Data(_))));
+}
+
+#[test]
+fn parse_does_not_allocate_from_header_size() {
+    let error = parse(b"P3\n200000 200000 255\n0 0 0").err().unwrap();
+
+    assert_eq!(
+        "invalid ppm pixel data: expected 120000000000 samples, found 3",
+        error.to_string()
+    );
+}
@@ -15,9 +15,9 @@ fn get_works_correctly() {
 
     let matrix = SquareMatrix::new(data.clone());
 
-    for row in 0..data.len() {
-        for col in 0..data[0].len() {
-            assert_eq!(data[row][col], matrix.get(row, col));
+    for (row, values) in data.iter().enumerate() {
+        for (col, value) in values.iter().enumerate() {
+            assert_eq!(*value, matrix.get(row, col));
         }
     }
 }
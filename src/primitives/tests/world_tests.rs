@@ -0,0 +1,139 @@
+use crate::{
+    objects::{Cube, Hittable, Plane, Sphere},
+    primitives::{Hit, Point, Ray, SquareMatrix, Vector},
+    scenes::sphere_field,
+};
+
+use super::World;
+
+fn as_comparable(hits: &[Hit]) -> Vec<(f64, usize)> {
+    let mut result: Vec<(f64, usize)> = hits
+        .iter()
+        .map(|hit| {
+            (
+                hit.get_t(),
+                hit.get_object() as *const dyn Hittable as *const () as usize,
+            )
+        })
+        .collect();
+    result.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+    result
+}
+
+fn sample_rays() -> Vec<Ray> {
+    let mut rays = Vec::new();
+    for index in 0..200 {
+        let seed = index as f64;
+        let origin = Point::new(
+            40. * (seed * 0.37).sin(),
+            1. + 10. * (seed * 0.13).cos().abs(),
+            40. * (seed * 0.71).cos(),
+        );
+        let direction = Vector::new(
+            (seed * 1.7).sin(),
+            -0.2 - (seed * 0.3).sin().abs(),
+            (seed * 2.3).cos(),
+        );
+        rays.push(Ray::new(origin, direction.get_normal()));
+    }
+    rays
+}
+
+#[test]
+fn get_hits_are_sorted_by_t() {
+    let mut world = World::new();
+    world.add_object(Sphere::identity());
+    world.add_object(Sphere::with_transform(SquareMatrix::translation(
+        0., 0., 3.,
+    )));
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+
+    let hits = world.get_hits(&ray);
+
+    let times: Vec<f64> = hits.iter().map(|hit| hit.get_t()).collect();
+    assert_eq!(vec![4., 6., 7., 9.], times);
+}
+
+#[test]
+fn add_object_discards_stale_bvh() {
+    let mut world = World::new();
+    world.add_object(Sphere::identity());
+    world.build_bvh();
+    assert!(world.get_bvh().is_some());
+
+    world.add_object(Sphere::identity());
+
+    assert!(world.get_bvh().is_none());
+}
+
+#[test]
+fn bvh_hits_match_brute_force_for_mixed_objects() {
+    let mut world = World::new();
+    world.add_object(Plane::with_transform(SquareMatrix::translation(
+        0., -1., 0.,
+    )));
+    for index in 0..50 {
+        let offset = index as f64;
+        world.add_object(Cube::with_transform(
+            &SquareMatrix::translation(offset - 25., offset % 3., offset % 7.)
+                * &SquareMatrix::rotation_y(offset),
+        ));
+        world.add_object(Sphere::with_transform(SquareMatrix::translation(
+            25. - offset,
+            offset % 5.,
+            -(offset % 4.),
+        )));
+    }
+    world.build_bvh();
+
+    for ray in sample_rays() {
+        let accelerated = world.get_hits(&ray);
+        let brute_force = world.get_hits_unaccelerated(&ray);
+
+        assert_eq!(as_comparable(&brute_force), as_comparable(&accelerated));
+    }
+}
+
+#[test]
+fn bvh_hits_match_brute_force_on_sphere_field() {
+    let (world, camera) = sphere_field::build(4, 4);
+    assert!(world.get_objects().len() > 4000);
+    assert!(world.get_bvh().is_some());
+
+    let mut rays = sample_rays();
+    for y in 0..4 {
+        for x in 0..4 {
+            rays.push(camera.get_ray(x, y).unwrap());
+        }
+    }
+    for ray in rays {
+        let accelerated = world.get_hits(&ray);
+        let brute_force = world.get_hits_unaccelerated(&ray);
+
+        assert_eq!(as_comparable(&brute_force), as_comparable(&accelerated));
+    }
+}
+
+#[test]
+fn get_hits_builds_bvh_on_demand() {
+    let mut world = World::new();
+    world.add_object(Sphere::identity());
+    assert!(world.get_bvh().is_none());
+
+    world.get_hits(&Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.)));
+
+    assert!(world.get_bvh().is_some());
+}
+
+#[test]
+fn brute_force_world_never_builds_bvh() {
+    let mut world = World::new();
+    world.add_object(Sphere::identity());
+    world.set_use_bvh(false);
+
+    let hits = world.get_hits(&Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.)));
+
+    assert_eq!(2, hits.len());
+    assert!(!world.get_use_bvh());
+    assert!(world.get_bvh().is_none());
+}
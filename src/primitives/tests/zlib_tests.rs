@@ -0,0 +1,176 @@
+use std::io::Write;
+
+use super::{
+    update_adler32, update_crc32, ZlibEncoder, DISTANCE_BASES, DISTANCE_EXTRA_BITS, LENGTH_BASES,
+    LENGTH_EXTRA_BITS,
+};
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn read_bits(&mut self, count: u32) -> usize {
+        let mut value = 0;
+        for index in 0..count {
+            let bit = (self.data[self.position / 8] >> (self.position % 8)) & 1;
+            value |= (bit as usize) << index;
+            self.position += 1;
+        }
+        value
+    }
+
+    fn read_huffman(&mut self, count: u32) -> usize {
+        (0..count).fold(0, |code, _| (code << 1) | self.read_bits(1))
+    }
+
+    fn read_fixed_literal(&mut self) -> usize {
+        let mut code = self.read_huffman(7);
+        if code <= 23 {
+            return 256 + code;
+        }
+        code = (code << 1) | self.read_bits(1);
+        match code {
+            0x30..=0xbf => code - 0x30,
+            0xc0..=0xc7 => 280 + code - 0xc0,
+            _ => 144 + ((code << 1) | self.read_bits(1)) - 0x190,
+        }
+    }
+}
+
+/// Minimal inflater for the block types the encoder produces.
+pub(crate) fn inflate(data: &[u8]) -> Vec<u8> {
+    assert_eq!(0x78, data[0]);
+    assert_eq!(0, (data[0] as usize * 256 + data[1] as usize) % 31);
+    let mut reader = BitReader { data, position: 16 };
+    let mut output: Vec<u8> = Vec::new();
+    loop {
+        let is_final = reader.read_bits(1) == 1;
+        match reader.read_bits(2) {
+            0 => {
+                reader.position = reader.position.div_ceil(8) * 8;
+                let length = reader.read_bits(16);
+                let complement = reader.read_bits(16);
+                assert_eq!(0xffff, length ^ complement);
+                let start = reader.position / 8;
+                output.extend_from_slice(&data[start..start + length]);
+                reader.position += length * 8;
+            }
+            1 => loop {
+                let value = reader.read_fixed_literal();
+                if value < 256 {
+                    output.push(value as u8);
+                    continue;
+                }
+                if value == 256 {
+                    break;
+                }
+                let code = value - 257;
+                let length = LENGTH_BASES[code] + reader.read_bits(LENGTH_EXTRA_BITS[code]);
+                let code = reader.read_huffman(5);
+                let distance = DISTANCE_BASES[code] + reader.read_bits(DISTANCE_EXTRA_BITS[code]);
+                for _ in 0..length {
+                    output.push(output[output.len() - distance]);
+                }
+            },
+            block_type => panic!("unexpected block type {}", block_type),
+        }
+        if is_final {
+            break;
+        }
+    }
+    let trailer = reader.position.div_ceil(8);
+    let adler = u32::from_be_bytes(data[trailer..trailer + 4].try_into().unwrap());
+    assert_eq!(update_adler32(1, &output), adler);
+    assert_eq!(trailer + 4, data.len());
+    output
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn noise(length: usize, mut state: u32) -> Vec<u8> {
+    (0..length)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn update_crc32_matches_reference_values() {
+    assert_eq!(0xcbf4_3926, update_crc32(0, b"123456789"));
+    assert_eq!(0xae42_6082, update_crc32(0, b"IEND"));
+    assert_eq!(
+        0xcbf4_3926,
+        update_crc32(update_crc32(0, b"1234"), b"56789")
+    );
+}
+
+#[test]
+fn update_adler32_matches_reference_value() {
+    assert_eq!(0x11e6_0398, update_adler32(1, b"Wikipedia"));
+}
+
+#[test]
+fn empty_input_round_trips() {
+    assert_eq!(Vec::<u8>::new(), inflate(&compress(&[])));
+}
+
+#[test]
+fn text_round_trips() {
+    let text = b"the quick brown fox jumps over the lazy dog, the quick brown fox";
+
+    assert_eq!(text.to_vec(), inflate(&compress(text)));
+}
+
+#[test]
+fn repetitive_data_compresses_across_blocks() {
+    let data: Vec<u8> = (0..300_000).map(|index| (index % 251) as u8).collect();
+
+    let compressed = compress(&data);
+
+    assert!(compressed.len() < data.len() / 20);
+    assert_eq!(data, inflate(&compressed));
+}
+
+#[test]
+fn matches_reach_back_into_the_window() {
+    let pattern = noise(20_000, 7);
+    let data = pattern.repeat(3);
+
+    let compressed = compress(&data);
+
+    assert!(compressed.len() < 30_000);
+    assert_eq!(data, inflate(&compressed));
+}
+
+#[test]
+fn incompressible_data_falls_back_to_stored_blocks() {
+    let data = noise(200_000, 42);
+
+    let compressed = compress(&data);
+
+    assert!(compressed.len() < data.len() + 100);
+    assert_eq!(data, inflate(&compressed));
+}
+
+#[test]
+fn many_small_writes_round_trip() {
+    let data: Vec<u8> = (0..150_000).map(|index| (index / 7 % 13) as u8).collect();
+    let mut encoder = ZlibEncoder::new(Vec::new());
+
+    for chunk in data.chunks(17) {
+        encoder.write_all(chunk).unwrap();
+    }
+    let compressed = encoder.finish().unwrap();
+
+    assert_eq!(data, inflate(&compressed));
+}
@@ -1,10 +1,14 @@
+use std::sync::OnceLock;
+
 use crate::{lighting::PointLight, objects::Hittable};
 
-use super::{Ray, Hit};
+use super::{Bvh, Hit, Ray};
 
 pub struct World {
     lights: Vec<PointLight>,
     objects: Vec<Box<dyn Hittable>>,
+    bvh: OnceLock<Bvh>,
+    use_bvh: bool,
 }
 
 impl World {
@@ -12,6 +16,8 @@ impl World {
         World {
             lights: Vec::new(),
             objects: Vec::new(),
+            bvh: OnceLock::new(),
+            use_bvh: true,
         }
     }
 
@@ -21,6 +27,31 @@ impl World {
 
     pub fn add_object<T: Hittable + 'static>(&mut self, object: T) {
         self.objects.push(Box::new(object));
+        self.bvh = OnceLock::new();
+    }
+
+    pub fn add_boxed_object(&mut self, object: Box<dyn Hittable>) {
+        self.objects.push(object);
+        self.bvh = OnceLock::new();
+    }
+
+    /// Builds the BVH up front; otherwise it is built by the first
+    /// `get_hits` after the objects change.
+    pub fn build_bvh(&mut self) {
+        self.bvh.get_or_init(|| Bvh::build(&self.objects));
+    }
+
+    pub fn get_bvh(&self) -> Option<&Bvh> {
+        self.bvh.get()
+    }
+
+    pub fn get_use_bvh(&self) -> bool {
+        self.use_bvh
+    }
+
+    /// Choosing `false` tests every object against every ray.
+    pub fn set_use_bvh(&mut self, use_bvh: bool) {
+        self.use_bvh = use_bvh;
     }
 
     pub fn get_lights(&self) -> &Vec<PointLight> {
@@ -31,21 +62,37 @@ impl World {
         &self.objects
     }
 
-    pub fn get_hits(&self, ray: &Ray) -> Vec<Hit> {
+    pub fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let mut result = if self.use_bvh {
+            self.bvh
+                .get_or_init(|| Bvh::build(&self.objects))
+                .get_hits(&self.objects, ray)
+        } else {
+            self.get_hits_unaccelerated(ray)
+        };
+        result.sort_unstable_by(|a, b| a.cmp_ignore_nan(b));
+        result
+    }
+
+    fn get_hits_unaccelerated(&self, ray: &Ray) -> Vec<Hit<'_>> {
         let mut result = Vec::new();
         for object in self.objects.iter() {
-            result.append(&mut object.get_hits(&ray));
+            result.append(&mut object.get_hits(ray));
         }
-        result.sort_unstable_by(|a, b| a.cmp_ignore_nan(b));
         result
     }
 
-    pub fn get_first_visible_hit<'a>(hits: &'a Vec<Hit>) -> Option<&'a Hit<'a>> {
-        for hit in hits.iter() {
-            if hit.get_t() > 0.0 {
-                return Some(hit);
-            }
-        }
-        None
+    pub fn get_first_visible_hit<'a, 'b>(hits: &'b [Hit<'a>]) -> Option<&'b Hit<'a>> {
+        hits.iter().find(|hit| hit.get_t() > 0.0)
     }
 }
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/world_tests.rs"]
+mod tests;
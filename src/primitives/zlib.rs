@@ -0,0 +1,350 @@
+use std::io::{Result, Write};
+
+const WINDOW_SIZE: usize = 1 << 15;
+const BLOCK_SIZE: usize = 1 << 16;
+const HASH_SIZE: usize = 1 << 15;
+const MAX_CHAIN: usize = 64;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const MAX_STORED_BLOCK: usize = 65535;
+const NONE: usize = usize::MAX;
+
+const LENGTH_BASES: [usize; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA_BITS: [u32; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASES: [usize; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA_BITS: [u32; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+const CRC_TABLE: [u32; 256] = build_crc_table();
+
+const fn build_crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut value = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 == 1 {
+                0xedb8_8320 ^ (value >> 1)
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[index] = value;
+        index += 1;
+    }
+    table
+}
+
+/// Extends a finished CRC-32 with more data; pass 0 to start a new checksum.
+pub fn update_crc32(crc: u32, data: &[u8]) -> u32 {
+    !data.iter().fold(!crc, |crc, &byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn update_adler32(adler: u32, data: &[u8]) -> u32 {
+    let mut a = adler & 0xffff;
+    let mut b = adler >> 16;
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+enum Symbol {
+    Literal(u8),
+    Match { length: usize, distance: usize },
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write_bits(&mut self, value: u32, count: u32) {
+        self.buffer |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn write_huffman(&mut self, code: u32, length: u32) {
+        self.write_bits(code.reverse_bits() >> (32 - length), length);
+    }
+
+    fn align_to_byte(&mut self) {
+        if self.count > 0 {
+            self.write_bits(0, 8 - self.count);
+        }
+    }
+}
+
+/// Streaming zlib compressor using fixed-Huffman deflate blocks, falling back
+/// to stored blocks for data that does not compress.
+pub struct ZlibEncoder<W: Write> {
+    inner: W,
+    bits: BitWriter,
+    data: Vec<u8>,
+    base: usize,
+    pending: usize,
+    head: Vec<usize>,
+    prev: Vec<usize>,
+    adler: u32,
+}
+
+impl<W: Write> ZlibEncoder<W> {
+    pub fn new(inner: W) -> Self {
+        let mut bits = BitWriter::default();
+        bits.bytes.extend_from_slice(&[0x78, 0x01]);
+        ZlibEncoder {
+            inner,
+            bits,
+            data: Vec::new(),
+            base: 0,
+            pending: 0,
+            head: vec![NONE; HASH_SIZE],
+            prev: vec![NONE; WINDOW_SIZE],
+            adler: 1,
+        }
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        self.compress_block(true);
+        self.bits.align_to_byte();
+        let adler = self.adler;
+        self.bits.bytes.extend_from_slice(&adler.to_be_bytes());
+        self.flush_bits()?;
+        Ok(self.inner)
+    }
+
+    fn flush_bits(&mut self) -> Result<()> {
+        if !self.bits.bytes.is_empty() {
+            self.inner.write_all(&self.bits.bytes)?;
+            self.bits.bytes.clear();
+        }
+        Ok(())
+    }
+
+    fn hash(&self, position: usize) -> usize {
+        let index = position - self.base;
+        let bytes = &self.data[index..index + MIN_MATCH];
+        let value = (bytes[0] as usize) << 16 | (bytes[1] as usize) << 8 | bytes[2] as usize;
+        (value.wrapping_mul(2_654_435_761) >> 7) & (HASH_SIZE - 1)
+    }
+
+    fn insert(&mut self, position: usize, end: usize) {
+        if position + MIN_MATCH <= end {
+            let hash = self.hash(position);
+            self.prev[position % WINDOW_SIZE] = self.head[hash];
+            self.head[hash] = position;
+        }
+    }
+
+    fn find_match(&self, position: usize, end: usize) -> Option<(usize, usize)> {
+        if position + MIN_MATCH > end {
+            return None;
+        }
+        let max_length = MAX_MATCH.min(end - position);
+        let current = &self.data[position - self.base..end - self.base];
+        let mut candidate = self.head[self.hash(position)];
+        let mut best: Option<(usize, usize)> = None;
+        for _ in 0..MAX_CHAIN {
+            if candidate == NONE || candidate >= position || position - candidate > WINDOW_SIZE {
+                break;
+            }
+            let previous = &self.data[candidate - self.base..];
+            let length = current
+                .iter()
+                .zip(previous)
+                .take(max_length)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if length >= MIN_MATCH && best.is_none_or(|(best, _)| length > best) {
+                best = Some((length, position - candidate));
+                if length == max_length {
+                    break;
+                }
+            }
+            let next = self.prev[candidate % WINDOW_SIZE];
+            if next >= candidate {
+                break;
+            }
+            candidate = next;
+        }
+        best
+    }
+
+    fn compress_block(&mut self, is_final: bool) {
+        let start = self.base + self.pending;
+        let end = self.base + self.data.len();
+        let mut symbols = Vec::new();
+        let mut position = start;
+        while position < end {
+            match self.find_match(position, end) {
+                Some((length, distance)) => {
+                    symbols.push(Symbol::Match { length, distance });
+                    for offset in 0..length {
+                        self.insert(position + offset, end);
+                    }
+                    position += length;
+                }
+                None => {
+                    symbols.push(Symbol::Literal(self.data[position - self.base]));
+                    self.insert(position, end);
+                    position += 1;
+                }
+            }
+        }
+
+        let block = &self.data[start - self.base..];
+        let fixed_bits: usize = symbols.iter().map(symbol_cost).sum::<usize>() + 10;
+        let stored_bits = (block.len() + 5 * block.len().div_ceil(MAX_STORED_BLOCK).max(1)) * 8;
+        if fixed_bits <= stored_bits {
+            write_fixed_block(&mut self.bits, &symbols, is_final);
+        } else {
+            write_stored_blocks(&mut self.bits, block, is_final);
+        }
+
+        self.adler = update_adler32(self.adler, block);
+        let keep = self.data.len().min(WINDOW_SIZE);
+        let dropped = self.data.len() - keep;
+        self.data.drain(..dropped);
+        self.base += dropped;
+        self.pending = self.data.len();
+    }
+}
+
+impl<W: Write> Write for ZlibEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.data.extend_from_slice(buf);
+        if self.data.len() - self.pending >= BLOCK_SIZE {
+            self.compress_block(false);
+            self.flush_bits()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.flush_bits()?;
+        self.inner.flush()
+    }
+}
+
+fn length_code(length: usize) -> usize {
+    LENGTH_BASES
+        .iter()
+        .rposition(|&base| base <= length)
+        .unwrap()
+}
+
+fn distance_code(distance: usize) -> usize {
+    DISTANCE_BASES
+        .iter()
+        .rposition(|&base| base <= distance)
+        .unwrap()
+}
+
+fn literal_cost(value: usize) -> usize {
+    match value {
+        0..=143 => 8,
+        144..=255 => 9,
+        256..=279 => 7,
+        _ => 8,
+    }
+}
+
+fn symbol_cost(symbol: &Symbol) -> usize {
+    match symbol {
+        Symbol::Literal(byte) => literal_cost(*byte as usize),
+        Symbol::Match { length, distance } => {
+            let length_code = length_code(*length);
+            let distance_code = distance_code(*distance);
+            literal_cost(257 + length_code)
+                + LENGTH_EXTRA_BITS[length_code] as usize
+                + 5
+                + DISTANCE_EXTRA_BITS[distance_code] as usize
+        }
+    }
+}
+
+fn write_literal(bits: &mut BitWriter, value: usize) {
+    let value = value as u32;
+    match value {
+        0..=143 => bits.write_huffman(0x30 + value, 8),
+        144..=255 => bits.write_huffman(0x190 + value - 144, 9),
+        256..=279 => bits.write_huffman(value - 256, 7),
+        _ => bits.write_huffman(0xc0 + value - 280, 8),
+    }
+}
+
+fn write_fixed_block(bits: &mut BitWriter, symbols: &[Symbol], is_final: bool) {
+    bits.write_bits(is_final as u32, 1);
+    bits.write_bits(1, 2);
+    for symbol in symbols {
+        match symbol {
+            Symbol::Literal(byte) => write_literal(bits, *byte as usize),
+            Symbol::Match { length, distance } => {
+                let length_code = length_code(*length);
+                write_literal(bits, 257 + length_code);
+                bits.write_bits(
+                    (length - LENGTH_BASES[length_code]) as u32,
+                    LENGTH_EXTRA_BITS[length_code],
+                );
+                let distance_code = distance_code(*distance);
+                bits.write_huffman(distance_code as u32, 5);
+                bits.write_bits(
+                    (distance - DISTANCE_BASES[distance_code]) as u32,
+                    DISTANCE_EXTRA_BITS[distance_code],
+                );
+            }
+        }
+    }
+    write_literal(bits, 256);
+}
+
+fn write_stored_blocks(bits: &mut BitWriter, data: &[u8], is_final: bool) {
+    let mut chunks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if chunks.peek().is_none() {
+        write_stored_block(bits, &[], is_final);
+    }
+    while let Some(chunk) = chunks.next() {
+        write_stored_block(bits, chunk, is_final && chunks.peek().is_none());
+    }
+}
+
+fn write_stored_block(bits: &mut BitWriter, data: &[u8], is_final: bool) {
+    bits.write_bits(is_final as u32, 1);
+    bits.write_bits(0, 2);
+    bits.align_to_byte();
+    let length = data.len() as u16;
+    bits.bytes.extend_from_slice(&length.to_le_bytes());
+    bits.bytes.extend_from_slice(&(!length).to_le_bytes());
+    bits.bytes.extend_from_slice(data);
+}
+
+#[cfg(test)]
+#[path = "tests/zlib_tests.rs"]
+pub(super) mod tests;
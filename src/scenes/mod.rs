@@ -1 +1,2 @@
 pub mod three_balls_in_a_room;
+pub mod sphere_field;
\ No newline at end of file
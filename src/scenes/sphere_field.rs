@@ -0,0 +1,57 @@
+use std::f64::consts::PI;
+
+use crate::{
+    cameras::Camera,
+    lighting::PointLight,
+    objects::{Hittable, Plane, Sphere},
+    primitives::{Point, RgbColor, SquareMatrix, Vector, World},
+    shading::Material,
+};
+
+pub const SPHERES_PER_SIDE: usize = 64;
+
+pub fn build(width: usize, height: usize) -> (World, Camera) {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(-20., 30., -20.),
+        RgbColor::new(1., 1., 1.),
+    ));
+
+    let mut floor = Plane::identity();
+    let mut floor_material = Material::default();
+    floor_material.set_color(RgbColor::new(0.9, 0.9, 0.9));
+    floor_material.set_specular(0.0);
+    floor.set_material(floor_material);
+    world.add_object(floor);
+
+    let half = SPHERES_PER_SIDE as f64 / 2.;
+    for row in 0..SPHERES_PER_SIDE {
+        for col in 0..SPHERES_PER_SIDE {
+            let seed = (row * SPHERES_PER_SIDE + col) as f64;
+            let radius = 0.2 + 0.15 * (seed * 0.37).sin().abs();
+            let x = col as f64 - half + 0.25 * (seed * 1.3).cos();
+            let z = row as f64 - half + 0.25 * (seed * 0.7).sin();
+            let mut sphere = Sphere::with_transform(
+                &SquareMatrix::translation(x, radius, z)
+                    * &SquareMatrix::scaling(radius, radius, radius),
+            );
+            let mut material = Material::default();
+            material.set_color(RgbColor::new(
+                0.5 + 0.5 * (seed * 0.11).sin(),
+                0.5 + 0.5 * (seed * 0.23).cos(),
+                0.5 + 0.5 * (seed * 0.05).sin(),
+            ));
+            sphere.set_material(material);
+            world.add_object(sphere);
+        }
+    }
+    world.build_bvh();
+
+    let mut camera = Camera::new(width, height, PI / 3.);
+    camera.set_position(
+        &Point::new(0., 12., -half - 10.),
+        &Point::new(0., 0., 0.),
+        &Vector::new(0., 1., 0.),
+    );
+    (world, camera)
+}
@@ -3,12 +3,12 @@ use std::f64::consts::PI;
 use crate::{
     cameras::Camera,
     lighting::PointLight,
-    objects::{Hittable, Sphere},
-    primitives::{Canvas, Point, RgbColor, SquareMatrix, Vector, World},
+    objects::{Hittable, Plane, Sphere},
+    primitives::{Point, RgbColor, SquareMatrix, Vector, World},
     shading::Material,
 };
 
-pub fn build(canvas: &Canvas) -> (World, Camera) {
+pub fn build(width: usize, height: usize) -> (World, Camera) {
     let mut world = World::new();
     world.add_light(PointLight::new(
         Point::new(-10., 6., -10.),
@@ -19,8 +19,7 @@ pub fn build(canvas: &Canvas) -> (World, Camera) {
         RgbColor::new(0.5, 0.5, 0.5),
     ));
 
-    let mut floor = Sphere::identity();
-    floor.set_transform(SquareMatrix::scaling(10., 0.01, 10.));
+    let mut floor = Plane::identity();
     let mut floor_material = Material::default();
     floor_material.set_color(RgbColor::new(1., 0.9, 0.9));
     floor_material.set_specular(0.0);
@@ -35,13 +34,11 @@ pub fn build(canvas: &Canvas) -> (World, Camera) {
     let mut left_transform =
         &SquareMatrix::translation(0., 0., 5.) * &SquareMatrix::rotation_y(-PI / 4.);
     left_transform = &left_transform * &SquareMatrix::rotation_x(PI / 2.);
-    left_transform = &left_transform * &SquareMatrix::scaling(10., 0.01, 10.);
     left_wall.set_transform(left_transform);
 
     let mut right_transform =
         &SquareMatrix::translation(0., 0., 5.) * &SquareMatrix::rotation_y(PI / 4.);
     right_transform = &right_transform * &SquareMatrix::rotation_x(PI / 2.);
-    right_transform = &right_transform * &SquareMatrix::scaling(10., 0.01, 10.);
     right_wall.set_transform(right_transform);
     world.add_object(floor);
     world.add_object(left_wall);
@@ -75,7 +72,7 @@ pub fn build(canvas: &Canvas) -> (World, Camera) {
     left.set_material(left_material);
     world.add_object(left);
 
-    let mut camera = Camera::new(&canvas, PI / 2.5);
+    let mut camera = Camera::new(width, height, PI / 2.5);
     camera.set_position(
         &Point::new(0., 1.5, -5.),
         &Point::new(0., 1., 0.),
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::primitives::{Point, RgbColor, SquareMatrix};
+
+use super::Pattern;
+
+#[derive(Clone, Debug)]
+pub struct BlendPattern {
+    a: Arc<dyn Pattern>,
+    b: Arc<dyn Pattern>,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+}
+
+impl BlendPattern {
+    pub fn new<A: Pattern + 'static, B: Pattern + 'static>(a: A, b: B) -> Self {
+        BlendPattern {
+            a: Arc::new(a),
+            b: Arc::new(b),
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+        }
+    }
+
+    pub fn get_a(&self) -> &dyn Pattern {
+        self.a.as_ref()
+    }
+
+    pub fn get_b(&self) -> &dyn Pattern {
+        self.b.as_ref()
+    }
+}
+
+impl Pattern for BlendPattern {
+    fn get_color(&self, pattern_point: &Point) -> RgbColor {
+        let a = self.a.get_nested_color(pattern_point);
+        let b = self.b.get_nested_color(pattern_point);
+        &(&a + &b) * 0.5
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/blend_pattern_tests.rs"]
+mod tests;
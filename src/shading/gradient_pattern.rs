@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::primitives::{Point, RgbColor, SquareMatrix};
+
+use super::{Pattern, SolidPattern};
+
+#[derive(Clone, Debug)]
+pub struct GradientPattern {
+    a: Arc<dyn Pattern>,
+    b: Arc<dyn Pattern>,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+}
+
+impl GradientPattern {
+    pub fn new(a: RgbColor, b: RgbColor) -> Self {
+        GradientPattern::with_patterns(SolidPattern::new(a), SolidPattern::new(b))
+    }
+
+    pub fn with_patterns<A: Pattern + 'static, B: Pattern + 'static>(a: A, b: B) -> Self {
+        GradientPattern {
+            a: Arc::new(a),
+            b: Arc::new(b),
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+        }
+    }
+
+    pub fn with_transform(a: RgbColor, b: RgbColor, transform: SquareMatrix) -> Self {
+        let mut pattern = GradientPattern::new(a, b);
+        pattern.set_transform(transform);
+        pattern
+    }
+
+    pub fn get_a(&self) -> &dyn Pattern {
+        self.a.as_ref()
+    }
+
+    pub fn get_b(&self) -> &dyn Pattern {
+        self.b.as_ref()
+    }
+}
+
+impl Pattern for GradientPattern {
+    fn get_color(&self, pattern_point: &Point) -> RgbColor {
+        let x = pattern_point.get_x();
+        let fraction = x - x.floor();
+        let a = self.a.get_nested_color(pattern_point);
+        let b = self.b.get_nested_color(pattern_point);
+        &a + &(&(&b - &a) * fraction)
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/gradient_pattern_tests.rs"]
+mod tests;
@@ -0,0 +1,143 @@
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use crate::{
+    primitives::{Canvas, Point, RgbColor, SquareMatrix},
+    tone_mapping::decode_srgb,
+};
+
+use super::{Pattern, UvMapping};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextureFilter {
+    Nearest,
+    Bilinear,
+}
+
+/// Wraps an image whose pixels are sRGB encoded, as read from PPM files, and
+/// returns linear colors.
+#[derive(Clone)]
+pub struct ImageTexturePattern {
+    image: Arc<Canvas>,
+    mapping: UvMapping,
+    filter: TextureFilter,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+}
+
+impl ImageTexturePattern {
+    pub fn new(image: Canvas, mapping: UvMapping) -> Self {
+        ImageTexturePattern::with_shared_image(Arc::new(image), mapping)
+    }
+
+    pub fn with_shared_image(image: Arc<Canvas>, mapping: UvMapping) -> Self {
+        ImageTexturePattern {
+            image,
+            mapping,
+            filter: TextureFilter::Bilinear,
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+        }
+    }
+
+    pub fn get_image(&self) -> &Canvas {
+        &self.image
+    }
+
+    pub fn get_mapping(&self) -> UvMapping {
+        self.mapping
+    }
+
+    pub fn set_mapping(&mut self, mapping: UvMapping) {
+        self.mapping = mapping;
+    }
+
+    pub fn get_filter(&self) -> TextureFilter {
+        self.filter
+    }
+
+    pub fn set_filter(&mut self, filter: TextureFilter) {
+        self.filter = filter;
+    }
+
+    /// Samples the image at texture coordinates with `v` pointing up, so
+    /// `(0, 0)` is the bottom-left corner. `u` wraps around, `v` is clamped.
+    pub fn get_color_at_uv(&self, u: f64, v: f64) -> RgbColor {
+        let width = self.image.get_width() as f64;
+        let height = self.image.get_height() as f64;
+        let x = u * width;
+        let y = (1.0 - v) * height;
+        match self.filter {
+            TextureFilter::Nearest => self.get_texel(x.floor() as isize, y.floor() as isize),
+            TextureFilter::Bilinear => {
+                let x = x - 0.5;
+                let y = y - 0.5;
+                let col = x.floor();
+                let row = y.floor();
+                let tx = x - col;
+                let ty = y - row;
+                let (col, row) = (col as isize, row as isize);
+                let top = lerp(&self.get_texel(col, row), &self.get_texel(col + 1, row), tx);
+                let bottom = lerp(
+                    &self.get_texel(col, row + 1),
+                    &self.get_texel(col + 1, row + 1),
+                    tx,
+                );
+                lerp(&top, &bottom, ty)
+            }
+        }
+    }
+
+    fn get_texel(&self, col: isize, row: isize) -> RgbColor {
+        let width = self.image.get_width() as isize;
+        let height = self.image.get_height() as isize;
+        let col = col.rem_euclid(width) as usize;
+        let row = row.clamp(0, height - 1) as usize;
+        let pixel = self.image.get_pixel(row, col);
+        RgbColor::new(
+            decode_srgb(pixel.get_red()),
+            decode_srgb(pixel.get_green()),
+            decode_srgb(pixel.get_blue()),
+        )
+    }
+}
+
+fn lerp(a: &RgbColor, b: &RgbColor, t: f64) -> RgbColor {
+    a + &(&(b - a) * t)
+}
+
+impl Debug for ImageTexturePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageTexturePattern")
+            .field("width", &self.image.get_width())
+            .field("height", &self.image.get_height())
+            .field("mapping", &self.mapping)
+            .field("filter", &self.filter)
+            .field("transform", &self.transform)
+            .finish()
+    }
+}
+
+impl Pattern for ImageTexturePattern {
+    fn get_color(&self, pattern_point: &Point) -> RgbColor {
+        let (u, v) = self.mapping.map(pattern_point);
+        self.get_color_at_uv(u, v)
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/image_texture_pattern_tests.rs"]
+mod tests;
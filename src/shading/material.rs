@@ -1,12 +1,23 @@
-use crate::primitives::RgbColor;
+use std::sync::Arc;
 
-#[derive(Clone, Debug, PartialEq)]
+use crate::{
+    objects::Hittable,
+    primitives::{Point, RgbColor},
+};
+
+use super::Pattern;
+
+#[derive(Clone, Debug)]
 pub struct Material {
     color: RgbColor,
+    pattern: Option<Arc<dyn Pattern>>,
     ambient: f64,
     diffuse: f64,
     specular: f64,
     shininess: f64,
+    reflective: f64,
+    transparency: f64,
+    refractive_index: f64,
 }
 
 impl Material {
@@ -18,6 +29,25 @@ impl Material {
         self.color = color;
     }
 
+    pub fn get_pattern(&self) -> Option<&dyn Pattern> {
+        self.pattern.as_deref()
+    }
+
+    pub fn set_pattern<T: Pattern + 'static>(&mut self, pattern: T) {
+        self.pattern = Some(Arc::new(pattern));
+    }
+
+    pub fn clear_pattern(&mut self) {
+        self.pattern = None;
+    }
+
+    pub fn get_color_at(&self, object: &dyn Hittable, world_point: &Point) -> RgbColor {
+        match &self.pattern {
+            Some(pattern) => pattern.get_color_at_object(object, world_point),
+            None => self.color.clone(),
+        }
+    }
+
     pub fn get_ambient(&self) -> f64 {
         self.ambient
     }
@@ -48,20 +78,67 @@ impl Material {
     pub fn set_shininess(&mut self, shininess: f64) {
         self.shininess = shininess;
     }
+
+    pub fn get_reflective(&self) -> f64 {
+        self.reflective
+    }
+
+    pub fn set_reflective(&mut self, reflective: f64) {
+        self.reflective = reflective;
+    }
+
+    pub fn get_transparency(&self) -> f64 {
+        self.transparency
+    }
+
+    pub fn set_transparency(&mut self, transparency: f64) {
+        self.transparency = transparency;
+    }
+
+    pub fn get_refractive_index(&self) -> f64 {
+        self.refractive_index
+    }
+
+    pub fn set_refractive_index(&mut self, refractive_index: f64) {
+        self.refractive_index = refractive_index;
+    }
 }
 
 impl Default for Material {
     fn default() -> Self {
         Self {
             color: RgbColor::new(1.0, 1.0, 1.0),
+            pattern: None,
             ambient: 0.2,
             diffuse: 0.85,
             specular: 0.8,
             shininess: 300.0,
+            reflective: 0.0,
+            transparency: 0.0,
+            refractive_index: 1.0,
         }
     }
 }
 
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (Some(one), Some(two)) => Arc::ptr_eq(one, two),
+            (None, None) => true,
+            _ => false,
+        };
+        same_pattern
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
+}
+
 #[cfg(test)]
 #[path = "tests/material_tests.rs"]
 mod tests;
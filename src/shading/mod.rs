@@ -1,3 +1,25 @@
+mod blend_pattern;
+mod checker_pattern;
+mod gradient_pattern;
+mod image_texture_pattern;
 mod material;
+mod pattern;
+mod perlin;
+mod perturbed_pattern;
+mod ring_pattern;
+mod solid_pattern;
+mod stripe_pattern;
+mod uv_mapping;
 
-pub use material::Material;
\ No newline at end of file
+pub use blend_pattern::BlendPattern;
+pub use checker_pattern::CheckerPattern;
+pub use gradient_pattern::GradientPattern;
+pub use image_texture_pattern::{ImageTexturePattern, TextureFilter};
+pub use material::Material;
+pub use pattern::Pattern;
+pub use perlin::{fractal_noise, noise};
+pub use perturbed_pattern::PerturbedPattern;
+pub use ring_pattern::RingPattern;
+pub use solid_pattern::SolidPattern;
+pub use stripe_pattern::StripePattern;
+pub use uv_mapping::{cube_map, cylindrical_map, planar_map, spherical_map, UvMapping};
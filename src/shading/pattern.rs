@@ -0,0 +1,23 @@
+use std::fmt::Debug;
+
+use crate::{
+    objects::Hittable,
+    primitives::{Point, RgbColor, SquareMatrix},
+};
+
+pub trait Pattern: Debug + Send + Sync {
+    fn get_color(&self, pattern_point: &Point) -> RgbColor;
+    fn get_transform(&self) -> &SquareMatrix;
+    fn get_transform_inverted(&self) -> &SquareMatrix;
+    fn set_transform(&mut self, transform: SquareMatrix);
+
+    fn get_nested_color(&self, parent_point: &Point) -> RgbColor {
+        self.get_color(&(self.get_transform_inverted() * parent_point))
+    }
+
+    fn get_color_at_object(&self, object: &dyn Hittable, world_point: &Point) -> RgbColor {
+        let object_point = object.get_transform_inverted() * world_point;
+        let pattern_point = self.get_transform_inverted() * &object_point;
+        self.get_color(&pattern_point)
+    }
+}
@@ -0,0 +1,116 @@
+const PERMUTATION: [usize; 512] = build_permutation();
+
+const fn build_permutation() -> [usize; 512] {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < 256 {
+        table[index] = index;
+        index += 1;
+    }
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut index = 255;
+    while index > 0 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let other = (state % (index as u64 + 1)) as usize;
+        let swap = table[index];
+        table[index] = table[other];
+        table[other] = swap;
+        index -= 1;
+    }
+    let mut result = [0; 512];
+    let mut index = 0;
+    while index < 512 {
+        result[index] = table[index % 256];
+        index += 1;
+    }
+    result
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+fn gradient(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    let u = if h & 1 == 0 { u } else { -u };
+    let v = if h & 2 == 0 { v } else { -v };
+    u + v
+}
+
+pub fn noise(x: f64, y: f64, z: f64) -> f64 {
+    let cell_x = x.floor().rem_euclid(256.0) as usize;
+    let cell_y = y.floor().rem_euclid(256.0) as usize;
+    let cell_z = z.floor().rem_euclid(256.0) as usize;
+    let x = x - x.floor();
+    let y = y - y.floor();
+    let z = z - z.floor();
+    let u = fade(x);
+    let v = fade(y);
+    let w = fade(z);
+
+    let p = &PERMUTATION;
+    let a = p[cell_x] + cell_y;
+    let aa = p[a] + cell_z;
+    let ab = p[a + 1] + cell_z;
+    let b = p[cell_x + 1] + cell_y;
+    let ba = p[b] + cell_z;
+    let bb = p[b + 1] + cell_z;
+
+    lerp(
+        w,
+        lerp(
+            v,
+            lerp(u, gradient(p[aa], x, y, z), gradient(p[ba], x - 1.0, y, z)),
+            lerp(
+                u,
+                gradient(p[ab], x, y - 1.0, z),
+                gradient(p[bb], x - 1.0, y - 1.0, z),
+            ),
+        ),
+        lerp(
+            v,
+            lerp(
+                u,
+                gradient(p[aa + 1], x, y, z - 1.0),
+                gradient(p[ba + 1], x - 1.0, y, z - 1.0),
+            ),
+            lerp(
+                u,
+                gradient(p[ab + 1], x, y - 1.0, z - 1.0),
+                gradient(p[bb + 1], x - 1.0, y - 1.0, z - 1.0),
+            ),
+        ),
+    )
+}
+
+pub fn fractal_noise(x: f64, y: f64, z: f64, octaves: usize) -> f64 {
+    let mut result = 0.0;
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+    let mut total_amplitude = 0.0;
+    for _ in 0..octaves.max(1) {
+        result += amplitude * noise(x * frequency, y * frequency, z * frequency);
+        total_amplitude += amplitude;
+        frequency *= 2.0;
+        amplitude *= 0.5;
+    }
+    result / total_amplitude
+}
+
+#[cfg(test)]
+#[path = "tests/perlin_tests.rs"]
+mod tests;
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::primitives::{Point, RgbColor, SquareMatrix};
+
+use super::{perlin, Pattern};
+
+#[derive(Clone, Debug)]
+pub struct PerturbedPattern {
+    pattern: Arc<dyn Pattern>,
+    scale: f64,
+    octaves: usize,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+}
+
+impl PerturbedPattern {
+    pub fn new<T: Pattern + 'static>(pattern: T, scale: f64) -> Self {
+        PerturbedPattern {
+            pattern: Arc::new(pattern),
+            scale,
+            octaves: 1,
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+        }
+    }
+
+    pub fn get_pattern(&self) -> &dyn Pattern {
+        self.pattern.as_ref()
+    }
+
+    pub fn get_scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f64) {
+        self.scale = scale;
+    }
+
+    pub fn get_octaves(&self) -> usize {
+        self.octaves
+    }
+
+    pub fn set_octaves(&mut self, octaves: usize) {
+        self.octaves = octaves;
+    }
+}
+
+impl Pattern for PerturbedPattern {
+    fn get_color(&self, pattern_point: &Point) -> RgbColor {
+        let x = pattern_point.get_x();
+        let y = pattern_point.get_y();
+        let z = pattern_point.get_z();
+        let jittered = Point::new(
+            x + self.scale * perlin::fractal_noise(x, y, z, self.octaves),
+            y + self.scale * perlin::fractal_noise(x, y, z + 1.0, self.octaves),
+            z + self.scale * perlin::fractal_noise(x, y, z + 2.0, self.octaves),
+        );
+        self.pattern.get_nested_color(&jittered)
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/perturbed_pattern_tests.rs"]
+mod tests;
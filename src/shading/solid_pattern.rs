@@ -0,0 +1,39 @@
+use crate::primitives::{Point, RgbColor, SquareMatrix};
+
+use super::Pattern;
+
+#[derive(Clone, Debug)]
+pub struct SolidPattern {
+    color: RgbColor,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+}
+
+impl SolidPattern {
+    pub fn new(color: RgbColor) -> Self {
+        SolidPattern {
+            color,
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+        }
+    }
+}
+
+impl Pattern for SolidPattern {
+    fn get_color(&self, _pattern_point: &Point) -> RgbColor {
+        self.color.clone()
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
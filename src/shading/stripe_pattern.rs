@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::primitives::{Point, RgbColor, SquareMatrix};
+
+use super::{Pattern, SolidPattern};
+
+const EPSILON: f64 = 1e-9;
+
+#[derive(Clone, Debug)]
+pub struct StripePattern {
+    a: Arc<dyn Pattern>,
+    b: Arc<dyn Pattern>,
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+}
+
+impl StripePattern {
+    pub fn new(a: RgbColor, b: RgbColor) -> Self {
+        StripePattern::with_patterns(SolidPattern::new(a), SolidPattern::new(b))
+    }
+
+    pub fn with_patterns<A: Pattern + 'static, B: Pattern + 'static>(a: A, b: B) -> Self {
+        StripePattern {
+            a: Arc::new(a),
+            b: Arc::new(b),
+            transform: SquareMatrix::identity(4),
+            inverted: SquareMatrix::identity(4),
+        }
+    }
+
+    pub fn with_transform(a: RgbColor, b: RgbColor, transform: SquareMatrix) -> Self {
+        let mut pattern = StripePattern::new(a, b);
+        pattern.set_transform(transform);
+        pattern
+    }
+
+    pub fn get_a(&self) -> &dyn Pattern {
+        self.a.as_ref()
+    }
+
+    pub fn get_b(&self) -> &dyn Pattern {
+        self.b.as_ref()
+    }
+}
+
+impl Pattern for StripePattern {
+    fn get_color(&self, pattern_point: &Point) -> RgbColor {
+        if (pattern_point.get_x() + EPSILON).floor().rem_euclid(2.0) == 0.0 {
+            self.a.get_nested_color(pattern_point)
+        } else {
+            self.b.get_nested_color(pattern_point)
+        }
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/stripe_pattern_tests.rs"]
+mod tests;
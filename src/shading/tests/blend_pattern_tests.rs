@@ -0,0 +1,59 @@
+use crate::{
+    primitives::{Point, RgbColor, SquareMatrix},
+    shading::{CheckerPattern, Pattern, SolidPattern, StripePattern},
+};
+
+use super::BlendPattern;
+
+fn white() -> RgbColor {
+    RgbColor::new(1., 1., 1.)
+}
+
+fn black() -> RgbColor {
+    RgbColor::new(0., 0., 0.)
+}
+
+#[test]
+fn get_color_averages_both_patterns() {
+    let pattern = BlendPattern::new(
+        SolidPattern::new(RgbColor::new(1., 0.5, 0.)),
+        SolidPattern::new(RgbColor::new(0., 0.5, 1.)),
+    );
+
+    assert_eq!(
+        RgbColor::new(0.5, 0.5, 0.5),
+        pattern.get_color(&Point::new(0., 0., 0.))
+    );
+}
+
+#[test]
+fn get_color_applies_each_sub_pattern_transform() {
+    let mut rotated = StripePattern::new(white(), black());
+    rotated.set_transform(SquareMatrix::rotation_y(std::f64::consts::FRAC_PI_2));
+    let pattern = BlendPattern::new(StripePattern::new(white(), black()), rotated);
+
+    let grey = RgbColor::new(0.5, 0.5, 0.5);
+    assert_eq!(white(), pattern.get_color(&Point::new(0.5, 0., -0.5)));
+    assert_eq!(grey, pattern.get_color(&Point::new(1.5, 0., -0.5)));
+    assert_eq!(grey, pattern.get_color(&Point::new(0.5, 0., 0.5)));
+    assert_eq!(black(), pattern.get_color(&Point::new(1.5, 0., 0.5)));
+}
+
+#[test]
+fn nested_checkers_of_stripes_use_sub_pattern_colors() {
+    let red = RgbColor::new(1., 0., 0.);
+    let blue = RgbColor::new(0., 0., 1.);
+    let pattern = CheckerPattern::with_patterns(
+        StripePattern::new(white(), black()),
+        StripePattern::with_transform(
+            red.clone(),
+            blue.clone(),
+            SquareMatrix::scaling(0.5, 1., 1.),
+        ),
+    );
+
+    assert_eq!(white(), pattern.get_color(&Point::new(0.25, 0., 0.25)));
+    assert_eq!(red, pattern.get_color(&Point::new(1.25, 0., 0.25)));
+    assert_eq!(blue, pattern.get_color(&Point::new(1.75, 0., 0.25)));
+    assert_eq!(black(), pattern.get_color(&Point::new(1.25, 0., 1.25)));
+}
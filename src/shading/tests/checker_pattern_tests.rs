@@ -0,0 +1,38 @@
+use crate::{
+    primitives::{Point, RgbColor},
+    shading::Pattern,
+};
+
+use super::CheckerPattern;
+
+fn checkers() -> CheckerPattern {
+    CheckerPattern::new(RgbColor::new(1., 1., 1.), RgbColor::new(0., 0., 0.))
+}
+
+#[test]
+fn get_color_repeats_in_each_axis() {
+    let pattern = checkers();
+    let white = RgbColor::new(1., 1., 1.);
+    let black = RgbColor::new(0., 0., 0.);
+
+    for axis in 0..3 {
+        let point = |value: f64| {
+            let mut coordinates = [0.; 3];
+            coordinates[axis] = value;
+            Point::new(coordinates[0], coordinates[1], coordinates[2])
+        };
+        assert_eq!(white, pattern.get_color(&point(0.)));
+        assert_eq!(white, pattern.get_color(&point(0.99)));
+        assert_eq!(black, pattern.get_color(&point(1.01)));
+    }
+}
+
+#[test]
+fn get_color_is_stable_on_cell_boundaries() {
+    let pattern = checkers();
+
+    assert_eq!(
+        pattern.get_color(&Point::new(0.5, 0., 0.5)),
+        pattern.get_color(&Point::new(0.5, -1e-15, 0.5))
+    );
+}
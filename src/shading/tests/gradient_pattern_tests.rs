@@ -0,0 +1,35 @@
+use crate::{
+    primitives::{Point, RgbColor},
+    shading::Pattern,
+};
+
+use super::GradientPattern;
+
+#[test]
+fn get_color_interpolates_between_colors() {
+    let pattern = GradientPattern::new(RgbColor::new(1., 1., 1.), RgbColor::new(0., 0., 0.));
+    let cases = [
+        (0., RgbColor::new(1., 1., 1.)),
+        (0.25, RgbColor::new(0.75, 0.75, 0.75)),
+        (0.5, RgbColor::new(0.5, 0.5, 0.5)),
+        (0.75, RgbColor::new(0.25, 0.25, 0.25)),
+    ];
+
+    for (x, expected) in cases {
+        assert_eq!(expected, pattern.get_color(&Point::new(x, 0., 0.)));
+    }
+}
+
+#[test]
+fn get_color_repeats_every_unit() {
+    let pattern = GradientPattern::new(RgbColor::new(1., 0., 0.), RgbColor::new(0., 0., 1.));
+
+    assert_eq!(
+        pattern.get_color(&Point::new(0.5, 0., 0.)),
+        pattern.get_color(&Point::new(3.5, 7., -2.))
+    );
+    assert_eq!(
+        pattern.get_color(&Point::new(0.5, 0., 0.)),
+        pattern.get_color(&Point::new(-1.5, 0., 0.))
+    );
+}
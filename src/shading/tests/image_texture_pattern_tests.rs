@@ -0,0 +1,121 @@
+use crate::{
+    objects::Sphere,
+    primitives::{Canvas, Point, RgbColor},
+    shading::{Pattern, UvMapping},
+};
+
+use super::{ImageTexturePattern, TextureFilter};
+
+fn red() -> RgbColor {
+    RgbColor::new(1., 0., 0.)
+}
+
+fn green() -> RgbColor {
+    RgbColor::new(0., 1., 0.)
+}
+
+fn blue() -> RgbColor {
+    RgbColor::new(0., 0., 1.)
+}
+
+fn white() -> RgbColor {
+    RgbColor::new(1., 1., 1.)
+}
+
+fn image() -> Canvas {
+    let mut canvas = Canvas::new(2, 2, None);
+    canvas.set_pixel(0, 0, red());
+    canvas.set_pixel(0, 1, green());
+    canvas.set_pixel(1, 0, blue());
+    canvas.set_pixel(1, 1, white());
+    canvas
+}
+
+fn texture(filter: TextureFilter) -> ImageTexturePattern {
+    let mut pattern = ImageTexturePattern::new(image(), UvMapping::Planar);
+    pattern.set_filter(filter);
+    pattern
+}
+
+#[test]
+fn nearest_picks_pixel_with_v_pointing_up() {
+    let pattern = texture(TextureFilter::Nearest);
+
+    assert_eq!(red(), pattern.get_color_at_uv(0.1, 0.9));
+    assert_eq!(green(), pattern.get_color_at_uv(0.9, 0.9));
+    assert_eq!(blue(), pattern.get_color_at_uv(0.1, 0.1));
+    assert_eq!(white(), pattern.get_color_at_uv(0.9, 0.1));
+    assert_eq!(white(), pattern.get_color_at_uv(1.0 - 1e-12, 0.));
+}
+
+#[test]
+fn bilinear_is_exact_at_pixel_centres() {
+    let pattern = texture(TextureFilter::Bilinear);
+
+    assert_eq!(red(), pattern.get_color_at_uv(0.25, 0.75));
+    assert_eq!(white(), pattern.get_color_at_uv(0.75, 0.25));
+}
+
+#[test]
+fn bilinear_blends_neighbouring_pixels() {
+    let pattern = texture(TextureFilter::Bilinear);
+
+    assert_eq!(
+        RgbColor::new(0.5, 0.5, 0.5),
+        pattern.get_color_at_uv(0.5, 0.5)
+    );
+}
+
+#[test]
+fn bilinear_wraps_u_and_clamps_v() {
+    let pattern = texture(TextureFilter::Bilinear);
+
+    assert_eq!(RgbColor::new(0.5, 0.5, 0.), pattern.get_color_at_uv(0., 1.));
+}
+
+#[test]
+fn get_color_uses_mapping() {
+    let pattern = texture(TextureFilter::Nearest);
+
+    assert_eq!(blue(), pattern.get_color(&Point::new(0.25, 7., 0.25)));
+    assert_eq!(green(), pattern.get_color(&Point::new(2.75, 0., 0.75)));
+}
+
+#[test]
+fn spherical_texture_wraps_sphere() {
+    let mut pattern = ImageTexturePattern::new(image(), UvMapping::Spherical);
+    pattern.set_filter(TextureFilter::Nearest);
+    let sphere = Sphere::identity();
+
+    let top = pattern.get_color_at_object(&sphere, &Point::new(0., 0.9, -0.1));
+    let bottom = pattern.get_color_at_object(&sphere, &Point::new(0., -0.9, -0.1));
+
+    assert_eq!(red(), top);
+    assert_eq!(blue(), bottom);
+}
+
+#[test]
+fn texels_are_decoded_from_srgb() {
+    let mut image = Canvas::new(1, 1, None);
+    image.set_pixel(0, 0, RgbColor::new(0.5, 0.04, 1.));
+    let mut pattern = ImageTexturePattern::new(image, UvMapping::Planar);
+    pattern.set_filter(TextureFilter::Nearest);
+
+    let color = pattern.get_color_at_uv(0.5, 0.5);
+
+    assert!((0.21404 - color.get_red()).abs() < 1e-5);
+    assert!((0.04 / 12.92 - color.get_green()).abs() < 1e-9);
+    assert_eq!(1., color.get_blue());
+}
+
+#[test]
+fn bilinear_filter_blends_in_linear_space() {
+    let mut image = Canvas::new(2, 1, None);
+    image.set_pixel(0, 0, RgbColor::new(0., 0., 0.));
+    image.set_pixel(0, 1, RgbColor::new(1., 1., 1.));
+    let pattern = ImageTexturePattern::new(image, UvMapping::Planar);
+
+    let color = pattern.get_color_at_uv(0.5, 0.5);
+
+    assert!((0.5 - color.get_red()).abs() < 1e-9);
+}
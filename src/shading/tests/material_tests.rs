@@ -0,0 +1,93 @@
+use crate::{
+    objects::Sphere,
+    primitives::{Point, RgbColor},
+    shading::StripePattern,
+};
+
+use super::Material;
+
+#[test]
+fn default_material_has_expected_values() {
+    let material = Material::default();
+
+    assert_eq!(&RgbColor::new(1.0, 1.0, 1.0), material.get_color());
+    assert_eq!(0.2, material.get_ambient());
+    assert_eq!(0.85, material.get_diffuse());
+    assert_eq!(0.8, material.get_specular());
+    assert_eq!(300.0, material.get_shininess());
+    assert_eq!(0.0, material.get_reflective());
+    assert_eq!(0.0, material.get_transparency());
+    assert_eq!(1.0, material.get_refractive_index());
+}
+
+#[test]
+fn set_reflective_updates_value() {
+    let mut material = Material::default();
+
+    material.set_reflective(0.5);
+
+    assert_eq!(0.5, material.get_reflective());
+}
+
+#[test]
+fn set_transparency_and_refractive_index_update_values() {
+    let mut material = Material::default();
+
+    material.set_transparency(0.9);
+    material.set_refractive_index(1.5);
+
+    assert_eq!(0.9, material.get_transparency());
+    assert_eq!(1.5, material.get_refractive_index());
+}
+
+#[test]
+fn get_color_at_without_pattern_uses_flat_color() {
+    let mut material = Material::default();
+    material.set_color(RgbColor::new(0.1, 0.2, 0.3));
+
+    let color = material.get_color_at(&Sphere::identity(), &Point::new(5., 0., 0.));
+
+    assert!(material.get_pattern().is_none());
+    assert_eq!(RgbColor::new(0.1, 0.2, 0.3), color);
+}
+
+#[test]
+fn get_color_at_with_pattern_uses_pattern() {
+    let mut material = Material::default();
+    material.set_pattern(StripePattern::new(
+        RgbColor::new(1., 1., 1.),
+        RgbColor::new(0., 0., 0.),
+    ));
+    let sphere = Sphere::identity();
+
+    assert!(material.get_pattern().is_some());
+    assert_eq!(
+        RgbColor::new(1., 1., 1.),
+        material.get_color_at(&sphere, &Point::new(0.9, 0., 0.))
+    );
+    assert_eq!(
+        RgbColor::new(0., 0., 0.),
+        material.get_color_at(&sphere, &Point::new(1.1, 0., 0.))
+    );
+
+    material.clear_pattern();
+
+    assert!(material.get_pattern().is_none());
+}
+
+#[test]
+fn clone_shares_pattern_and_compares_equal() {
+    let mut material = Material::default();
+    material.set_pattern(StripePattern::new(
+        RgbColor::new(1., 1., 1.),
+        RgbColor::new(0., 0., 0.),
+    ));
+    let mut other = Material::default();
+    other.set_pattern(StripePattern::new(
+        RgbColor::new(1., 1., 1.),
+        RgbColor::new(0., 0., 0.),
+    ));
+
+    assert_eq!(material, material.clone());
+    assert_ne!(material, other);
+}
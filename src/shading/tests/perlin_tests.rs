@@ -0,0 +1,38 @@
+use super::{fractal_noise, noise};
+
+#[test]
+fn noise_is_zero_on_lattice_points() {
+    for (x, y, z) in [(0., 0., 0.), (1., 2., 3.), (-4., 7., -255.), (300., 0., 1.)] {
+        assert_eq!(0., noise(x, y, z));
+    }
+}
+
+#[test]
+fn noise_stays_within_unit_range() {
+    for index in 0..10_000 {
+        let t = index as f64 * 0.0137;
+        let value = noise(t * 1.3, -t * 0.7, t * 2.1 + 0.5);
+        assert!((-1. ..=1.).contains(&value), "{value}");
+    }
+}
+
+#[test]
+fn noise_varies_between_lattice_points() {
+    let values: Vec<f64> = (0..50)
+        .map(|index| noise(index as f64 * 0.31 + 0.1, 0.45, 0.77))
+        .collect();
+
+    assert!(values.iter().any(|value| *value > 0.05));
+    assert!(values.iter().any(|value| *value < -0.05));
+}
+
+#[test]
+fn noise_is_periodic_every_256_cells() {
+    assert_eq!(noise(0.25, 0.5, 0.75), noise(256.25, 0.5, -255.25));
+}
+
+#[test]
+fn fractal_noise_with_one_octave_matches_noise() {
+    assert_eq!(noise(0.3, 1.6, 2.9), fractal_noise(0.3, 1.6, 2.9, 1));
+    assert_eq!(noise(0.3, 1.6, 2.9), fractal_noise(0.3, 1.6, 2.9, 0));
+}
@@ -0,0 +1,63 @@
+use crate::{
+    primitives::{Point, RgbColor},
+    shading::{GradientPattern, Pattern, StripePattern},
+};
+
+use super::PerturbedPattern;
+
+fn stripes() -> StripePattern {
+    StripePattern::new(RgbColor::new(1., 1., 1.), RgbColor::new(0., 0., 0.))
+}
+
+fn sample_points() -> impl Iterator<Item = Point> {
+    (0..200).map(|index| {
+        let t = index as f64 * 0.173;
+        Point::new(t.sin() * 3., t * 0.37, t.cos() * 2.)
+    })
+}
+
+#[test]
+fn zero_scale_matches_inner_pattern() {
+    let pattern = PerturbedPattern::new(stripes(), 0.);
+
+    for point in sample_points() {
+        assert_eq!(stripes().get_color(&point), pattern.get_color(&point));
+    }
+}
+
+#[test]
+fn non_zero_scale_moves_stripe_boundaries() {
+    let pattern = PerturbedPattern::new(stripes(), 0.8);
+
+    let changed = sample_points()
+        .filter(|point| stripes().get_color(point) != pattern.get_color(point))
+        .count();
+
+    assert!(changed > 0);
+}
+
+#[test]
+fn get_color_is_deterministic() {
+    let mut first = PerturbedPattern::new(stripes(), 0.5);
+    first.set_octaves(3);
+    let mut second = PerturbedPattern::new(stripes(), 0.5);
+    second.set_octaves(3);
+
+    for point in sample_points() {
+        assert_eq!(first.get_color(&point), second.get_color(&point));
+    }
+}
+
+#[test]
+fn get_color_is_continuous_for_continuous_inner_pattern() {
+    let gradient = GradientPattern::new(RgbColor::new(0., 0., 0.), RgbColor::new(1., 1., 1.));
+    let mut pattern = PerturbedPattern::new(gradient, 0.2);
+    pattern.set_octaves(4);
+
+    for point in sample_points() {
+        let nearby = Point::new(point.get_x() + 1e-6, point.get_y(), point.get_z());
+        let a = pattern.get_color(&point);
+        let b = pattern.get_color(&nearby);
+        assert!((a.get_red() - b.get_red()).abs() < 1e-3);
+    }
+}
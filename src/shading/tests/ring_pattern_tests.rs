@@ -0,0 +1,24 @@
+use crate::{
+    primitives::{Point, RgbColor},
+    shading::Pattern,
+};
+
+use super::RingPattern;
+
+#[test]
+fn get_color_extends_in_x_and_z() {
+    let white = RgbColor::new(1., 1., 1.);
+    let black = RgbColor::new(0., 0., 0.);
+    let pattern = RingPattern::new(white.clone(), black.clone());
+    let cases = [
+        (Point::new(0., 0., 0.), white.clone()),
+        (Point::new(1., 0., 0.), black.clone()),
+        (Point::new(0., 0., 1.), black.clone()),
+        (Point::new(0.708, 0., 0.708), black.clone()),
+        (Point::new(2., 5., 0.), white),
+    ];
+
+    for (point, expected) in cases {
+        assert_eq!(expected, pattern.get_color(&point));
+    }
+}
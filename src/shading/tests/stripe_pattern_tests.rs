@@ -0,0 +1,85 @@
+use crate::{
+    objects::{Hittable, Sphere},
+    primitives::{Point, RgbColor, SquareMatrix},
+    shading::Pattern,
+};
+
+use super::StripePattern;
+
+fn white() -> RgbColor {
+    RgbColor::new(1., 1., 1.)
+}
+
+fn black() -> RgbColor {
+    RgbColor::new(0., 0., 0.)
+}
+
+#[test]
+fn new_stores_both_colors() {
+    let pattern = StripePattern::new(white(), black());
+
+    let origin = Point::new(0., 0., 0.);
+    assert_eq!(white(), pattern.get_a().get_color(&origin));
+    assert_eq!(black(), pattern.get_b().get_color(&origin));
+    assert_eq!(&SquareMatrix::identity(4), pattern.get_transform());
+}
+
+#[test]
+fn get_color_is_constant_in_y_and_z() {
+    let pattern = StripePattern::new(white(), black());
+
+    for value in [0., 1., 2.] {
+        assert_eq!(white(), pattern.get_color(&Point::new(0., value, 0.)));
+        assert_eq!(white(), pattern.get_color(&Point::new(0., 0., value)));
+    }
+}
+
+#[test]
+fn get_color_alternates_in_x() {
+    let pattern = StripePattern::new(white(), black());
+    let cases = [
+        (0., white()),
+        (0.9, white()),
+        (1., black()),
+        (-0.1, black()),
+        (-1., black()),
+        (-1.1, white()),
+    ];
+
+    for (x, expected) in cases {
+        assert_eq!(expected, pattern.get_color(&Point::new(x, 0., 0.)));
+    }
+}
+
+#[test]
+fn get_color_at_object_uses_object_transform() {
+    let sphere = Sphere::with_transform(SquareMatrix::scaling(2., 2., 2.));
+    let pattern = StripePattern::new(white(), black());
+
+    let color = pattern.get_color_at_object(&sphere, &Point::new(1.5, 0., 0.));
+
+    assert_eq!(white(), color);
+}
+
+#[test]
+fn get_color_at_object_uses_pattern_transform() {
+    let sphere = Sphere::identity();
+    let pattern =
+        StripePattern::with_transform(white(), black(), SquareMatrix::scaling(2., 2., 2.));
+
+    let color = pattern.get_color_at_object(&sphere, &Point::new(1.5, 0., 0.));
+
+    assert_eq!(white(), color);
+}
+
+#[test]
+fn get_color_at_object_uses_both_transforms() {
+    let sphere = Sphere::with_transform(SquareMatrix::scaling(2., 2., 2.));
+    let pattern =
+        StripePattern::with_transform(white(), black(), SquareMatrix::translation(0.5, 0., 0.));
+
+    let color = pattern.get_color_at_object(&sphere, &Point::new(2.5, 0., 0.));
+
+    assert_eq!(white(), color);
+    assert_eq!(sphere.get_transform(), &SquareMatrix::scaling(2., 2., 2.));
+}
@@ -0,0 +1,115 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use crate::primitives::Point;
+
+use super::{cube_map, cylindrical_map, planar_map, spherical_map, UvMapping};
+
+const EPSILON: f64 = 1e-9;
+
+fn assert_uv(expected: (f64, f64), actual: (f64, f64)) {
+    assert!(
+        (expected.0 - actual.0).abs() < EPSILON && (expected.1 - actual.1).abs() < EPSILON,
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn spherical_map_on_unit_sphere() {
+    let cases = [
+        (Point::new(0., 0., -1.), (0., 0.5)),
+        (Point::new(1., 0., 0.), (0.25, 0.5)),
+        (Point::new(0., 0., 1.), (0.5, 0.5)),
+        (Point::new(-1., 0., 0.), (0.75, 0.5)),
+        (Point::new(0., 1., 0.), (0.5, 1.)),
+        (Point::new(0., -1., 0.), (0.5, 0.)),
+        (Point::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0.), (0.25, 0.75)),
+    ];
+
+    for (point, expected) in cases {
+        assert_uv(expected, spherical_map(&point));
+    }
+}
+
+#[test]
+fn planar_map_repeats_every_unit() {
+    let cases = [
+        (Point::new(0.25, 0., 0.5), (0.25, 0.5)),
+        (Point::new(0.25, 0., -0.25), (0.25, 0.75)),
+        (Point::new(0.25, 0.5, -0.25), (0.25, 0.75)),
+        (Point::new(1.25, 0., 0.5), (0.25, 0.5)),
+        (Point::new(0.25, 0., -1.75), (0.25, 0.25)),
+        (Point::new(1., 0., -1.), (0., 0.)),
+    ];
+
+    for (point, expected) in cases {
+        assert_uv(expected, planar_map(&point));
+    }
+}
+
+#[test]
+fn cylindrical_map_wraps_around_y_axis() {
+    let cases = [
+        (Point::new(0., 0., -1.), (0., 0.)),
+        (Point::new(0., 0.5, -1.), (0., 0.5)),
+        (Point::new(0., 1., -1.), (0., 0.)),
+        (Point::new(FRAC_1_SQRT_2, 0.5, -FRAC_1_SQRT_2), (0.125, 0.5)),
+        (Point::new(1., 0.5, 0.), (0.25, 0.5)),
+        (Point::new(0., -0.25, 1.), (0.5, 0.75)),
+        (Point::new(-1., 1.25, 0.), (0.75, 0.25)),
+    ];
+
+    for (point, expected) in cases {
+        assert_uv(expected, cylindrical_map(&point));
+    }
+}
+
+#[test]
+fn cube_map_places_face_centres_in_cross_layout() {
+    let cases = [
+        (Point::new(-1., 0., 0.), (0.125, 0.5)),
+        (Point::new(0., 0., 1.), (0.375, 0.5)),
+        (Point::new(1., 0., 0.), (0.625, 0.5)),
+        (Point::new(0., 0., -1.), (0.875, 0.5)),
+        (Point::new(0., 1., 0.), (0.375, 2.5 / 3.)),
+        (Point::new(0., -1., 0.), (0.375, 0.5 / 3.)),
+    ];
+
+    for (point, expected) in cases {
+        assert_uv(expected, cube_map(&point));
+    }
+}
+
+#[test]
+fn cube_map_is_continuous_across_front_edges() {
+    let delta = 1e-7;
+    let pairs = [
+        (
+            Point::new(1. - delta, 0.3, 1.),
+            Point::new(1., 0.3, 1. - delta),
+        ),
+        (
+            Point::new(-0.3, 1. - delta, 1.),
+            Point::new(-0.3, 1., 1. - delta),
+        ),
+        (
+            Point::new(0.3, -1. + delta, 1.),
+            Point::new(0.3, -1., 1. - delta),
+        ),
+    ];
+
+    for (front, neighbour) in pairs {
+        let a = cube_map(&front);
+        let b = cube_map(&neighbour);
+        assert!((a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn map_dispatches_to_mapping_function() {
+    let point = Point::new(0.3, 0.4, -0.5);
+
+    assert_eq!(spherical_map(&point), UvMapping::Spherical.map(&point));
+    assert_eq!(planar_map(&point), UvMapping::Planar.map(&point));
+    assert_eq!(cylindrical_map(&point), UvMapping::Cylindrical.map(&point));
+    assert_eq!(cube_map(&point), UvMapping::Cube.map(&point));
+}
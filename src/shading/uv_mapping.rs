@@ -0,0 +1,73 @@
+use std::f64::consts::PI;
+
+use crate::primitives::Point;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UvMapping {
+    Spherical,
+    Planar,
+    Cylindrical,
+    /// Unfolds the six faces of the unit cube into a horizontal cross: the
+    /// middle row holds left, front, right and back, with up and down above
+    /// and below the front face.
+    Cube,
+}
+
+impl UvMapping {
+    pub fn map(&self, point: &Point) -> (f64, f64) {
+        match self {
+            UvMapping::Spherical => spherical_map(point),
+            UvMapping::Planar => planar_map(point),
+            UvMapping::Cylindrical => cylindrical_map(point),
+            UvMapping::Cube => cube_map(point),
+        }
+    }
+}
+
+pub fn spherical_map(point: &Point) -> (f64, f64) {
+    let (x, y, z) = (point.get_x(), point.get_y(), point.get_z());
+    let radius = (x * x + y * y + z * z).sqrt();
+    if radius == 0.0 {
+        return (0.0, 0.0);
+    }
+    let theta = x.atan2(z);
+    let phi = (y / radius).clamp(-1.0, 1.0).acos();
+    let u = 1.0 - (theta / (2.0 * PI) + 0.5);
+    let v = 1.0 - phi / PI;
+    (u, v)
+}
+
+pub fn planar_map(point: &Point) -> (f64, f64) {
+    (point.get_x().rem_euclid(1.0), point.get_z().rem_euclid(1.0))
+}
+
+pub fn cylindrical_map(point: &Point) -> (f64, f64) {
+    let theta = point.get_x().atan2(point.get_z());
+    let u = 1.0 - (theta / (2.0 * PI) + 0.5);
+    let v = point.get_y().rem_euclid(1.0);
+    (u, v)
+}
+
+pub fn cube_map(point: &Point) -> (f64, f64) {
+    let (x, y, z) = (point.get_x(), point.get_y(), point.get_z());
+    let side = |value: f64| ((value + 1.0) / 2.0).clamp(0.0, 1.0);
+    let max = x.abs().max(y.abs()).max(z.abs());
+    let (column, row, u, v) = if max == x {
+        (2.0, 1.0, side(-z), side(y))
+    } else if max == -x {
+        (0.0, 1.0, side(z), side(y))
+    } else if max == y {
+        (1.0, 2.0, side(x), side(-z))
+    } else if max == -y {
+        (1.0, 0.0, side(x), side(z))
+    } else if max == z {
+        (1.0, 1.0, side(x), side(y))
+    } else {
+        (3.0, 1.0, side(-x), side(y))
+    };
+    ((column + u) / 4.0, (row + v) / 3.0)
+}
+
+#[cfg(test)]
+#[path = "tests/uv_mapping_tests.rs"]
+mod tests;
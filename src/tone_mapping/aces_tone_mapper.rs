@@ -0,0 +1,32 @@
+use crate::primitives::RgbColor;
+
+use super::{clamp_tone_mapper::clamp_channels, ToneMapper};
+
+/// Krzysztof Narkowicz's curve fit of the ACES filmic reference transform.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AcesToneMapper;
+
+impl AcesToneMapper {
+    pub fn new() -> Self {
+        AcesToneMapper
+    }
+}
+
+fn aces_curve(value: f64) -> f64 {
+    let value = value.max(0.0);
+    (value * (2.51 * value + 0.03)) / (value * (2.43 * value + 0.59) + 0.14)
+}
+
+impl ToneMapper for AcesToneMapper {
+    fn map(&self, color: &RgbColor) -> RgbColor {
+        clamp_channels(&RgbColor::new(
+            aces_curve(color.get_red()),
+            aces_curve(color.get_green()),
+            aces_curve(color.get_blue()),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/aces_tone_mapper_tests.rs"]
+mod tests;
@@ -0,0 +1,37 @@
+use crate::primitives::RgbColor;
+
+use super::ToneMapper;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ClampToneMapper;
+
+impl ClampToneMapper {
+    pub fn new() -> Self {
+        ClampToneMapper
+    }
+}
+
+pub(super) fn clamp_channels(color: &RgbColor) -> RgbColor {
+    let clamp = |value: f64| {
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        }
+    };
+    RgbColor::new(
+        clamp(color.get_red()),
+        clamp(color.get_green()),
+        clamp(color.get_blue()),
+    )
+}
+
+impl ToneMapper for ClampToneMapper {
+    fn map(&self, color: &RgbColor) -> RgbColor {
+        clamp_channels(color)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/clamp_tone_mapper_tests.rs"]
+mod tests;
@@ -0,0 +1,32 @@
+use crate::primitives::RgbColor;
+
+use super::{clamp_tone_mapper::clamp_channels, ToneMapper};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExposureToneMapper {
+    stops: f64,
+}
+
+impl ExposureToneMapper {
+    pub fn new(stops: f64) -> Self {
+        ExposureToneMapper { stops }
+    }
+
+    pub fn get_stops(&self) -> f64 {
+        self.stops
+    }
+
+    pub fn set_stops(&mut self, stops: f64) {
+        self.stops = stops;
+    }
+}
+
+impl ToneMapper for ExposureToneMapper {
+    fn map(&self, color: &RgbColor) -> RgbColor {
+        clamp_channels(&(color * 2f64.powf(self.stops)))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/exposure_tone_mapper_tests.rs"]
+mod tests;
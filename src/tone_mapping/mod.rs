@@ -0,0 +1,13 @@
+mod aces_tone_mapper;
+mod clamp_tone_mapper;
+mod exposure_tone_mapper;
+mod reinhard_tone_mapper;
+mod srgb;
+mod tone_mapper;
+
+pub use aces_tone_mapper::AcesToneMapper;
+pub use clamp_tone_mapper::ClampToneMapper;
+pub use exposure_tone_mapper::ExposureToneMapper;
+pub use reinhard_tone_mapper::ReinhardToneMapper;
+pub use srgb::{decode_srgb, encode_srgb};
+pub use tone_mapper::ToneMapper;
@@ -0,0 +1,54 @@
+use crate::primitives::RgbColor;
+
+use super::{clamp_tone_mapper::clamp_channels, ToneMapper};
+
+/// Reinhard's global operator applied to luminance, so hues are preserved.
+/// Luminance at or above the white point maps to pure white.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReinhardToneMapper {
+    white_point: f64,
+}
+
+impl ReinhardToneMapper {
+    pub fn new() -> Self {
+        ReinhardToneMapper {
+            white_point: f64::INFINITY,
+        }
+    }
+
+    pub fn with_white_point(white_point: f64) -> Self {
+        ReinhardToneMapper { white_point }
+    }
+
+    pub fn get_white_point(&self) -> f64 {
+        self.white_point
+    }
+
+    pub fn set_white_point(&mut self, white_point: f64) {
+        self.white_point = white_point;
+    }
+}
+
+impl Default for ReinhardToneMapper {
+    fn default() -> Self {
+        ReinhardToneMapper::new()
+    }
+}
+
+impl ToneMapper for ReinhardToneMapper {
+    fn map(&self, color: &RgbColor) -> RgbColor {
+        let luminance = 0.2126 * color.get_red().max(0.0)
+            + 0.7152 * color.get_green().max(0.0)
+            + 0.0722 * color.get_blue().max(0.0);
+        if luminance <= 0.0 || luminance.is_nan() {
+            return RgbColor::new(0.0, 0.0, 0.0);
+        }
+        let white_squared = self.white_point * self.white_point;
+        let mapped = luminance * (1.0 + luminance / white_squared) / (1.0 + luminance);
+        clamp_channels(&(color * (mapped / luminance)))
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/reinhard_tone_mapper_tests.rs"]
+mod tests;
@@ -0,0 +1,26 @@
+/// Applies the sRGB transfer function to a linear value, clamping it to
+/// `[0, 1]` first.
+pub fn encode_srgb(linear: f64) -> f64 {
+    let linear = if linear.is_nan() {
+        0.0
+    } else {
+        linear.clamp(0.0, 1.0)
+    };
+    if linear <= 0.003_130_8 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+pub fn decode_srgb(encoded: f64) -> f64 {
+    if encoded <= 0.040_45 {
+        encoded / 12.92
+    } else {
+        ((encoded + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/srgb_tests.rs"]
+mod tests;
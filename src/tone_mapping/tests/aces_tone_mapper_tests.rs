@@ -0,0 +1,29 @@
+use crate::{primitives::RgbColor, tone_mapping::ToneMapper};
+
+use super::AcesToneMapper;
+
+#[test]
+fn map_follows_filmic_curve() {
+    let mapper = AcesToneMapper::new();
+
+    let mapped = mapper.map(&RgbColor::new(0., 1., 0.18));
+
+    assert_eq!(0., mapped.get_red());
+    assert!((0.803_80 - mapped.get_green()).abs() < 1e-5);
+    assert!((0.266_90 - mapped.get_blue()).abs() < 1e-5);
+}
+
+#[test]
+fn map_is_monotonic_and_saturates() {
+    let mapper = AcesToneMapper::new();
+    let mut previous = -1.;
+
+    for index in 0..200 {
+        let value = mapper
+            .map(&RgbColor::new(index as f64 * 0.1, 0., 0.))
+            .get_red();
+        assert!(value >= previous);
+        previous = value;
+    }
+    assert_eq!(1., mapper.map(&RgbColor::new(1000., 0., 0.)).get_red());
+}
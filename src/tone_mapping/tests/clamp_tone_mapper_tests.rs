@@ -0,0 +1,24 @@
+use crate::{primitives::RgbColor, tone_mapping::ToneMapper};
+
+use super::ClampToneMapper;
+
+#[test]
+fn map_clamps_each_channel() {
+    let mapper = ClampToneMapper::new();
+
+    assert_eq!(
+        RgbColor::new(1., 0.25, 0.),
+        mapper.map(&RgbColor::new(7., 0.25, -1.))
+    );
+    assert_eq!(
+        RgbColor::new(0., 0., 0.),
+        mapper.map(&RgbColor::new(f64::NAN, 0., 0.))
+    );
+}
+
+#[test]
+fn encode_applies_srgb_transfer() {
+    let mapper = ClampToneMapper::new();
+
+    assert_eq!((0, 188, 255), mapper.encode(&RgbColor::new(0., 0.5, 2.)));
+}
@@ -0,0 +1,30 @@
+use crate::{primitives::RgbColor, tone_mapping::ToneMapper};
+
+use super::ExposureToneMapper;
+
+#[test]
+fn map_scales_by_powers_of_two() {
+    let color = RgbColor::new(0.25, 0.125, 0.5);
+
+    assert_eq!(
+        RgbColor::new(0.5, 0.25, 1.),
+        ExposureToneMapper::new(1.).map(&color)
+    );
+    assert_eq!(
+        RgbColor::new(0.125, 0.0625, 0.25),
+        ExposureToneMapper::new(-1.).map(&color)
+    );
+    assert_eq!(color, ExposureToneMapper::new(0.).map(&color));
+}
+
+#[test]
+fn map_clamps_after_scaling() {
+    let mut mapper = ExposureToneMapper::new(0.);
+    mapper.set_stops(2.);
+
+    assert_eq!(2., mapper.get_stops());
+    assert_eq!(
+        RgbColor::new(1., 0.4, 0.),
+        mapper.map(&RgbColor::new(0.5, 0.1, -0.2))
+    );
+}
@@ -0,0 +1,42 @@
+use crate::{primitives::RgbColor, tone_mapping::ToneMapper};
+
+use super::ReinhardToneMapper;
+
+const EPSILON: f64 = 1e-9;
+
+#[test]
+fn map_compresses_luminance() {
+    let mapper = ReinhardToneMapper::new();
+
+    let grey = mapper.map(&RgbColor::new(1., 1., 1.));
+    let bright = mapper.map(&RgbColor::new(99., 99., 99.));
+
+    assert!((0.5 - grey.get_red()).abs() < EPSILON);
+    assert!((0.99 - bright.get_green()).abs() < EPSILON);
+    assert_eq!(
+        RgbColor::new(0., 0., 0.),
+        mapper.map(&RgbColor::new(0., 0., 0.))
+    );
+}
+
+#[test]
+fn map_preserves_channel_ratios_below_saturation() {
+    let mapper = ReinhardToneMapper::new();
+
+    let mapped = mapper.map(&RgbColor::new(0.8, 0.4, 0.2));
+
+    assert!((mapped.get_red() - 2. * mapped.get_green()).abs() < EPSILON);
+    assert!((mapped.get_green() - 2. * mapped.get_blue()).abs() < EPSILON);
+}
+
+#[test]
+fn white_point_maps_to_white() {
+    let mut mapper = ReinhardToneMapper::with_white_point(4.);
+
+    let mapped = mapper.map(&RgbColor::new(4., 4., 4.));
+    assert!((1. - mapped.get_red()).abs() < EPSILON);
+
+    mapper.set_white_point(8.);
+    assert_eq!(8., mapper.get_white_point());
+    assert!(mapper.map(&RgbColor::new(4., 4., 4.)).get_red() < 1.);
+}
@@ -0,0 +1,26 @@
+use super::{decode_srgb, encode_srgb};
+
+const EPSILON: f64 = 1e-4;
+
+#[test]
+fn encode_srgb_matches_reference_values() {
+    assert_eq!(0., encode_srgb(0.));
+    assert!((1. - encode_srgb(1.)).abs() < 1e-12);
+    assert!((0.735_36 - encode_srgb(0.5)).abs() < EPSILON);
+    assert!((0.025_84 - encode_srgb(0.002)).abs() < EPSILON);
+}
+
+#[test]
+fn encode_srgb_clamps_out_of_range_values() {
+    assert_eq!(0., encode_srgb(-3.));
+    assert_eq!(0., encode_srgb(f64::NAN));
+    assert!((1. - encode_srgb(40.)).abs() < 1e-12);
+}
+
+#[test]
+fn decode_srgb_inverts_encode_srgb() {
+    for index in 0..=100 {
+        let linear = index as f64 / 100.;
+        assert!((linear - decode_srgb(encode_srgb(linear))).abs() < 1e-9);
+    }
+}
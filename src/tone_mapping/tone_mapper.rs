@@ -0,0 +1,20 @@
+use std::fmt::Debug;
+
+use crate::primitives::RgbColor;
+
+use super::encode_srgb;
+
+pub trait ToneMapper: Debug + Send + Sync {
+    /// Maps linear scene radiance to linear display values in `[0, 1]`.
+    fn map(&self, color: &RgbColor) -> RgbColor;
+
+    fn encode(&self, color: &RgbColor) -> (u8, u8, u8) {
+        let mapped = self.map(color);
+        let to_byte = |value: f64| (encode_srgb(value) * 255.0).round() as u8;
+        (
+            to_byte(mapped.get_red()),
+            to_byte(mapped.get_green()),
+            to_byte(mapped.get_blue()),
+        )
+    }
+}
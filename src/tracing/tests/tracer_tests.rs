@@ -0,0 +1,870 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use crate::{
+    cameras::{
+        Camera, EquirectangularProjection, FisheyeProjection, OrthographicProjection,
+        SamplePattern, Sampler,
+    },
+    lighting::PointLight,
+    objects::{Hittable, Plane, Sphere},
+    primitives::{
+        BoundingBox, Canvas, Hit, HitRecord, Point, Ray, RgbColor, SquareMatrix, Vector, World,
+    },
+    scenes::three_balls_in_a_room,
+    shading::Material,
+};
+
+use super::Tracer;
+
+/// A minimal non-sphere shape: the object-space plane z = 0, facing -z.
+#[derive(Debug)]
+struct Wall {
+    transform: SquareMatrix,
+    inverted: SquareMatrix,
+    material: Material,
+}
+
+impl Wall {
+    fn new(transform: SquareMatrix) -> Self {
+        Wall {
+            inverted: transform.invert(),
+            transform,
+            material: Material::default(),
+        }
+    }
+}
+
+impl Hittable for Wall {
+    fn get_hits(&self, ray: &Ray) -> Vec<Hit<'_>> {
+        let ray = ray.transform(&self.inverted);
+        if ray.get_direction().get_z().abs() < 1e-9 {
+            return Vec::new();
+        }
+        vec![Hit::new(
+            -ray.get_origin().get_z() / ray.get_direction().get_z(),
+            self,
+        )]
+    }
+
+    fn get_normal(&self, _hit_point: &Point) -> Vector {
+        (&self.inverted.transpose() * &Vector::new(0., 0., -1.)).get_normal()
+    }
+
+    fn get_bounds(&self) -> BoundingBox {
+        BoundingBox::infinite()
+    }
+
+    fn get_material(&self) -> &Material {
+        &self.material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.material = material;
+    }
+
+    fn get_transform(&self) -> &SquareMatrix {
+        &self.transform
+    }
+
+    fn get_transform_inverted(&self) -> &SquareMatrix {
+        &self.inverted
+    }
+
+    fn set_transform(&mut self, transform: SquareMatrix) {
+        self.inverted = transform.invert();
+        self.transform = transform;
+    }
+}
+
+fn wall_world() -> World {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(0., 0., 0.),
+        RgbColor::new(1., 1., 1.),
+    ));
+    let mut wall = Wall::new(SquareMatrix::translation(0., 0., -5.));
+    let mut material = Material::default();
+    material.set_color(RgbColor::new(1., 0., 0.));
+    material.set_specular(0.);
+    wall.set_material(material);
+    world.add_object(wall);
+    world
+}
+
+#[test]
+fn hit_record_exposes_non_sphere_object() {
+    let world = wall_world();
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 0., -1.));
+
+    let hits = world.get_hits(&ray);
+    let hit = World::get_first_visible_hit(&hits).unwrap();
+    let record = hit.get_hit_record(&ray, &hits);
+
+    assert_eq!(5.0, record.get_t());
+    assert_eq!(
+        &RgbColor::new(1., 0., 0.),
+        record.get_object().get_material().get_color()
+    );
+    assert_eq!(&Vector::new(0., 0., 1.), record.get_normal());
+}
+
+#[test]
+fn trace_world_renders_non_sphere_object() {
+    let world = wall_world();
+    let mut canvas = Canvas::new(5, 5, None);
+    let camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 2.);
+
+    Tracer::trace_world(&world, &camera, &mut canvas);
+
+    for row in 0..canvas.get_height() {
+        for col in 0..canvas.get_width() {
+            let pixel = canvas.get_pixel(row, col);
+            assert!(pixel.get_red() > 0.);
+            assert_eq!(0., pixel.get_green());
+            assert_eq!(0., pixel.get_blue());
+        }
+    }
+    let centre = canvas.get_pixel(2, 2);
+    assert!((centre.get_red() - 1.05).abs() < 1e-9);
+}
+
+#[test]
+fn trace_world_leaves_background_when_nothing_is_hit() {
+    let world = World::new();
+    let mut canvas = Canvas::new(3, 3, None);
+    let camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 2.);
+
+    Tracer::trace_world(&world, &camera, &mut canvas);
+
+    assert_eq!(RgbColor::new(0., 0., 0.), canvas.get_pixel(1, 1));
+}
+
+fn assert_canvases_identical(expected: &Canvas, actual: &Canvas) {
+    assert_eq!(expected.get_width(), actual.get_width());
+    assert_eq!(expected.get_height(), actual.get_height());
+    for row in 0..expected.get_height() {
+        for col in 0..expected.get_width() {
+            assert_eq!(expected.get_pixel(row, col), actual.get_pixel(row, col));
+        }
+    }
+}
+
+#[test]
+fn split_into_tiles_covers_canvas_exactly_once() {
+    let tiles = Tracer::split_into_tiles(10, 7, 4);
+
+    assert_eq!(6, tiles.len());
+    let mut covered = vec![vec![0; 10]; 7];
+    for tile in tiles.iter() {
+        for row in covered.iter_mut().skip(tile.y).take(tile.height) {
+            for count in row.iter_mut().skip(tile.x).take(tile.width) {
+                *count += 1;
+            }
+        }
+    }
+    assert!(covered.iter().flatten().all(|count| *count == 1));
+}
+
+#[test]
+fn trace_world_parallel_matches_serial_output() {
+    let mut serial = Canvas::new(37, 23, None);
+    let (world, camera) = three_balls_in_a_room::build(37, 23);
+    Tracer::trace_world(&world, &camera, &mut serial);
+
+    for (threads, tile_size) in [(1, 64), (3, 5), (8, 1)] {
+        let mut parallel = Canvas::new(37, 23, None);
+        Tracer::trace_world_parallel(
+            &world,
+            &camera,
+            &mut parallel,
+            threads,
+            tile_size,
+            |_, _| {},
+        );
+
+        assert_canvases_identical(&serial, &parallel);
+    }
+}
+
+#[test]
+fn trace_world_parallel_keeps_background_for_misses() {
+    let background = RgbColor::new(0.25, 0.5, 0.75);
+    let mut serial = Canvas::new(6, 4, Some(background.clone()));
+    let mut parallel = Canvas::new(6, 4, Some(background));
+    let world = World::new();
+    let camera = Camera::new(serial.get_width(), serial.get_height(), PI / 2.);
+
+    Tracer::trace_world(&world, &camera, &mut serial);
+    Tracer::trace_world_parallel(&world, &camera, &mut parallel, 2, 3, |_, _| {});
+
+    assert_canvases_identical(&serial, &parallel);
+}
+
+#[test]
+fn trace_world_parallel_reports_progress_per_tile() {
+    let world = wall_world();
+    let mut canvas = Canvas::new(10, 10, None);
+    let camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 2.);
+    let mut progress = Vec::new();
+
+    Tracer::trace_world_parallel(&world, &camera, &mut canvas, 4, 3, |finished, total| {
+        progress.push((finished, total))
+    });
+
+    let expected: Vec<(usize, usize)> = (1..=16).map(|finished| (finished, 16)).collect();
+    assert_eq!(expected, progress);
+}
+
+fn book_material() -> Material {
+    let mut material = Material::default();
+    material.set_ambient(0.1);
+    material.set_diffuse(0.9);
+    material.set_specular(0.9);
+    material.set_shininess(200.);
+    material
+}
+
+fn default_world() -> World {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(-10., 10., -10.),
+        RgbColor::new(1., 1., 1.),
+    ));
+    let mut outer = Sphere::identity();
+    let mut outer_material = book_material();
+    outer_material.set_color(RgbColor::new(0.8, 1.0, 0.6));
+    outer_material.set_diffuse(0.7);
+    outer_material.set_specular(0.2);
+    outer.set_material(outer_material);
+    world.add_object(outer);
+    let mut inner = Sphere::with_transform(SquareMatrix::scaling(0.5, 0.5, 0.5));
+    inner.set_material(book_material());
+    world.add_object(inner);
+    world
+}
+
+fn reflective_floor(reflective: f64) -> Plane {
+    let mut floor = Plane::with_transform(SquareMatrix::translation(0., -1., 0.));
+    let mut material = book_material();
+    material.set_reflective(reflective);
+    floor.set_material(material);
+    floor
+}
+
+fn assert_color_near(expected: &RgbColor, actual: &RgbColor) {
+    assert!((expected.get_red() - actual.get_red()).abs() < 1e-3);
+    assert!((expected.get_green() - actual.get_green()).abs() < 1e-3);
+    assert!((expected.get_blue() - actual.get_blue()).abs() < 1e-3);
+}
+
+#[test]
+fn new_tracer_uses_default_max_depth() {
+    let mut tracer = Tracer::new();
+    assert_eq!(5, tracer.get_max_depth());
+
+    tracer.set_max_depth(2);
+
+    assert_eq!(2, tracer.get_max_depth());
+}
+
+#[test]
+fn color_at_returns_none_on_miss() {
+    let world = default_world();
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 1., 0.));
+
+    assert!(Tracer::new().color_at(&world, &ray).is_none());
+}
+
+#[test]
+fn color_at_non_reflective_surface_ignores_depth() {
+    let world = default_world();
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let mut shallow = Tracer::new();
+    shallow.set_max_depth(0);
+
+    let deep_color = Tracer::new().color_at(&world, &ray).unwrap();
+    let shallow_color = shallow.color_at(&world, &ray).unwrap();
+
+    assert_eq!(shallow_color, deep_color);
+    assert_color_near(&RgbColor::new(0.38066, 0.47583, 0.2855), &deep_color);
+}
+
+#[test]
+fn color_at_reflective_surface_adds_reflected_color() {
+    let mut world = default_world();
+    world.add_object(reflective_floor(0.5));
+    let half = FRAC_1_SQRT_2;
+    let ray = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -half, half));
+
+    let color = Tracer::new().color_at(&world, &ray).unwrap();
+
+    assert_color_near(&RgbColor::new(0.87677, 0.92436, 0.82918), &color);
+}
+
+#[test]
+fn color_at_reflective_surface_at_max_depth_skips_reflection() {
+    let mut world = default_world();
+    world.add_object(reflective_floor(0.5));
+    let mut matte_world = default_world();
+    matte_world.add_object(reflective_floor(0.0));
+    let half = FRAC_1_SQRT_2;
+    let ray = Ray::new(Point::new(0., 0., -3.), Vector::new(0., -half, half));
+    let mut tracer = Tracer::new();
+    tracer.set_max_depth(0);
+
+    let color = tracer.color_at(&world, &ray).unwrap();
+    let matte = tracer.color_at(&matte_world, &ray).unwrap();
+
+    assert_eq!(matte, color);
+}
+
+#[test]
+fn color_at_between_parallel_mirrors_terminates() {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(0., 0., 0.),
+        RgbColor::new(1., 1., 1.),
+    ));
+    let mut lower = Plane::with_transform(SquareMatrix::translation(0., -1., 0.));
+    let mut mirror = Material::default();
+    mirror.set_reflective(1.0);
+    lower.set_material(mirror.clone());
+    let mut upper = Plane::with_transform(SquareMatrix::translation(0., 1., 0.));
+    upper.set_material(mirror);
+    world.add_object(lower);
+    world.add_object(upper);
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 1., 0.));
+
+    assert!(Tracer::new().color_at(&world, &ray).is_some());
+}
+
+fn glass_sphere() -> Sphere {
+    let mut sphere = Sphere::identity();
+    let mut material = book_material();
+    material.set_transparency(1.0);
+    material.set_refractive_index(1.5);
+    sphere.set_material(material);
+    sphere
+}
+
+fn glass_floor(reflective: f64) -> Plane {
+    let mut floor = Plane::with_transform(SquareMatrix::translation(0., -1., 0.));
+    let mut material = book_material();
+    material.set_reflective(reflective);
+    material.set_transparency(0.5);
+    material.set_refractive_index(1.5);
+    floor.set_material(material);
+    floor
+}
+
+fn red_ball_below_floor() -> Sphere {
+    let mut ball = Sphere::with_transform(SquareMatrix::translation(0., -3.5, -0.5));
+    let mut material = book_material();
+    material.set_color(RgbColor::new(1., 0., 0.));
+    material.set_ambient(0.5);
+    ball.set_material(material);
+    ball
+}
+
+#[test]
+fn refracted_color_of_opaque_surface_is_black() {
+    let world = default_world();
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let hits = world.get_hits(&ray);
+    let record = hits[0].get_hit_record(&ray, &hits);
+
+    let color = Tracer::new().refracted_color(&world, &record, 5);
+
+    assert_eq!(RgbColor::new(0., 0., 0.), color);
+}
+
+#[test]
+fn refracted_color_at_max_depth_is_black() {
+    let mut world = World::new();
+    world.add_object(glass_sphere());
+    let ray = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+    let hits = world.get_hits(&ray);
+    let record = hits[0].get_hit_record(&ray, &hits);
+
+    let color = Tracer::new().refracted_color(&world, &record, 0);
+
+    assert_eq!(RgbColor::new(0., 0., 0.), color);
+}
+
+#[test]
+fn refracted_color_under_total_internal_reflection_is_black() {
+    let mut world = World::new();
+    world.add_object(glass_sphere());
+    let ray = Ray::new(Point::new(0., 0., FRAC_1_SQRT_2), Vector::new(0., 1., 0.));
+    let hits = world.get_hits(&ray);
+    let record = hits[1].get_hit_record(&ray, &hits);
+
+    let color = Tracer::new().refracted_color(&world, &record, 5);
+
+    assert!(record.get_is_inside());
+    assert_eq!(RgbColor::new(0., 0., 0.), color);
+}
+
+#[test]
+fn color_at_transparent_floor_shows_object_below() {
+    let mut world = default_world();
+    world.add_object(glass_floor(0.0));
+    world.add_object(red_ball_below_floor());
+    let ray = Ray::new(
+        Point::new(0., 0., -3.),
+        Vector::new(0., -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    );
+
+    let color = Tracer::new().color_at(&world, &ray).unwrap();
+
+    assert_color_near(&RgbColor::new(0.93642, 0.68642, 0.68642), &color);
+}
+
+#[test]
+fn color_at_reflective_transparent_floor_blends_with_schlick() {
+    let mut world = default_world();
+    world.add_object(glass_floor(0.5));
+    world.add_object(red_ball_below_floor());
+    let ray = Ray::new(
+        Point::new(0., 0., -3.),
+        Vector::new(0., -FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    );
+
+    let color = Tracer::new().color_at(&world, &ray).unwrap();
+
+    assert_color_near(&RgbColor::new(0.93391, 0.69643, 0.69243), &color);
+}
+
+#[test]
+fn schlick_under_total_internal_reflection_is_one() {
+    let sphere = glass_sphere();
+    let ray = Ray::new(Point::new(0., 0., FRAC_1_SQRT_2), Vector::new(0., 1., 0.));
+    let hits = sphere.get_hits(&ray);
+    let record = hits[1].get_hit_record(&ray, &hits);
+
+    assert_eq!(1.0, Tracer::schlick(&record));
+}
+
+#[test]
+fn schlick_with_perpendicular_ray_is_small() {
+    let sphere = glass_sphere();
+    let ray = Ray::new(Point::new(0., 0., 0.), Vector::new(0., 1., 0.));
+    let hits = sphere.get_hits(&ray);
+    let record = hits[1].get_hit_record(&ray, &hits);
+
+    assert!((Tracer::schlick(&record) - 0.04).abs() < 1e-5);
+}
+
+#[test]
+fn schlick_with_small_angle_and_denser_second_medium_is_significant() {
+    let sphere = glass_sphere();
+    let ray = Ray::new(Point::new(0., 0.99, -2.), Vector::new(0., 0., 1.));
+    let hits = sphere.get_hits(&ray);
+    let record = hits[0].get_hit_record(&ray, &hits);
+
+    assert!((Tracer::schlick(&record) - 0.48881).abs() < 1e-5);
+}
+
+#[test]
+fn set_epsilon_updates_value() {
+    let mut tracer = Tracer::new();
+    assert_eq!(1e-6, tracer.get_epsilon());
+
+    tracer.set_epsilon(1e-4);
+
+    assert_eq!(1e-4, tracer.get_epsilon());
+}
+
+#[test]
+fn over_point_at_grazing_angle_clears_the_surface() {
+    let plane = Plane::identity();
+    let epsilon = 1e-4;
+    for slope in [1e-1, 1e-3, 1e-5] {
+        let ray = Ray::new(
+            Point::new(0., slope * 10., -10.),
+            Vector::new(0., -slope, 1.).get_normal(),
+        );
+        let hits = plane.get_hits(&ray);
+        let record = HitRecord::with_epsilon(&hits[0], &ray, &hits, epsilon);
+
+        let along_ray = ray.at(record.get_t() - epsilon);
+        assert!(along_ray.get_y() < epsilon * slope * 1.01);
+        assert!((record.get_over_point().get_y() - epsilon).abs() < 1e-9);
+        assert!((record.get_under_point().get_y() + epsilon).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn grazing_hits_on_flattened_sphere_are_not_self_shadowed() {
+    let mut world = World::new();
+    world.add_object(Sphere::with_transform(SquareMatrix::scaling(
+        10., 0.01, 10.,
+    )));
+    world.add_object(Plane::with_transform(SquareMatrix::translation(
+        0., -2., 0.,
+    )));
+    let light = Point::new(-10., 6., -10.);
+    let mut tested = 0;
+    for index in 0..500 {
+        let step = index as f64;
+        let slope = 10f64.powf(-1. - 5. * step / 500.);
+        let ray = Ray::new(
+            Point::new(-4. + 8. * step / 500., 0.01 + slope * 12., -15.),
+            Vector::new(0.01 * step.sin(), -slope, 1.).get_normal(),
+        );
+        let hits = world.get_hits(&ray);
+        let hit = match World::get_first_visible_hit(&hits) {
+            Some(hit) => hit,
+            None => continue,
+        };
+        let record = HitRecord::new(hit, &ray, &hits);
+        let origin = record.get_over_point();
+        let ray_to_light = Ray::new(origin.clone(), &light - origin);
+
+        if &(&light - origin) * record.get_normal() > 0. {
+            tested += 1;
+            assert!(!Tracer::check_in_shadow(&world, &ray_to_light));
+        }
+    }
+    assert!(tested > 100);
+}
+
+#[test]
+fn grazing_hits_on_transformed_plane_are_not_self_shadowed() {
+    let mut world = World::new();
+    world.add_object(Plane::with_transform(
+        &SquareMatrix::rotation_z(0.3) * &SquareMatrix::rotation_x(-0.2),
+    ));
+    let mut tracer = Tracer::new();
+    tracer.set_epsilon(1e-8);
+    let light = PointLight::new(Point::new(0., 50., 0.), RgbColor::new(1., 1., 1.));
+    world.add_light(PointLight::new(
+        light.get_location().clone(),
+        light.get_intensity().clone(),
+    ));
+    for index in 1..200 {
+        let slope = 10f64.powf(-(index as f64) / 40.);
+        let ray = Ray::new(
+            Point::new(-20., 20. * slope + 5., -20.),
+            Vector::new(1., -slope, 1.).get_normal(),
+        );
+        let hits = world.get_hits(&ray);
+        let hit = match World::get_first_visible_hit(&hits) {
+            Some(hit) => hit,
+            None => continue,
+        };
+        let record = HitRecord::with_epsilon(hit, &ray, &hits, tracer.get_epsilon());
+
+        let color = tracer.color_at(&world, &ray).unwrap();
+
+        assert_eq!(light.shade(&record), color);
+    }
+}
+
+fn small_sphere_world() -> World {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(0., 0., 0.),
+        RgbColor::new(1., 1., 1.),
+    ));
+    let mut sphere = Sphere::with_transform(
+        &SquareMatrix::translation(0., 0., -5.) * &SquareMatrix::scaling(0.5, 0.5, 0.5),
+    );
+    let mut material = Material::default();
+    material.set_color(RgbColor::new(1., 0.5, 0.));
+    material.set_specular(0.);
+    sphere.set_material(material);
+    world.add_object(sphere);
+    world
+}
+
+#[test]
+fn grid_supersampling_averages_hits_with_background() {
+    let world = small_sphere_world();
+    let background = RgbColor::new(0., 0., 1.);
+    let mut single = Canvas::new(1, 1, Some(background.clone()));
+    let mut supersampled = Canvas::new(1, 1, Some(background.clone()));
+    let camera = Camera::new(single.get_width(), single.get_height(), PI / 2.);
+    let mut tracer = Tracer::new();
+
+    tracer.render(&world, &camera, &mut single);
+    tracer.set_sampler(Sampler::new(SamplePattern::Grid, 9));
+    tracer.render(&world, &camera, &mut supersampled);
+
+    let hit = single.get_pixel(0, 0);
+    let expected = &(&hit + &(&background * 8.)) * (1. / 9.);
+    assert_ne!(background, hit);
+    assert_eq!(expected, supersampled.get_pixel(0, 0));
+}
+
+#[test]
+fn supersampling_keeps_background_when_every_sample_misses() {
+    let background = RgbColor::new(0.25, 0.5, 0.75);
+    let mut canvas = Canvas::new(4, 4, Some(background.clone()));
+    let camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 2.);
+    let mut tracer = Tracer::new();
+    tracer.set_sampler(Sampler::new(SamplePattern::Jittered, 8));
+
+    tracer.render(&World::new(), &camera, &mut canvas);
+
+    for row in 0..4 {
+        for col in 0..4 {
+            assert_eq!(background, canvas.get_pixel(row, col));
+        }
+    }
+}
+
+#[test]
+fn supersampled_parallel_render_matches_serial() {
+    let mut serial = Canvas::new(19, 13, None);
+    let (world, camera) = three_balls_in_a_room::build(19, 13);
+    let mut tracer = Tracer::new();
+    let mut sampler = Sampler::new(SamplePattern::Sobol, 4);
+    sampler.set_seed(17);
+    tracer.set_sampler(sampler);
+    tracer.render(&world, &camera, &mut serial);
+
+    let mut parallel = Canvas::new(19, 13, None);
+    tracer.render_parallel(&world, &camera, &mut parallel, 3, 4, |_, _| {});
+
+    assert_canvases_identical(&serial, &parallel);
+}
+
+#[test]
+fn jittered_render_depends_only_on_seed() {
+    let mut first = Canvas::new(12, 8, None);
+    let mut second = Canvas::new(12, 8, None);
+    let mut other_seed = Canvas::new(12, 8, None);
+    let (world, camera) = three_balls_in_a_room::build(12, 8);
+    let mut tracer = Tracer::new();
+    let mut sampler = Sampler::new(SamplePattern::Jittered, 4);
+    sampler.set_seed(1);
+    tracer.set_sampler(sampler.clone());
+
+    tracer.render(&world, &camera, &mut first);
+    tracer.render(&world, &camera, &mut second);
+    sampler.set_seed(2);
+    tracer.set_sampler(sampler);
+    tracer.render(&world, &camera, &mut other_seed);
+
+    assert_canvases_identical(&first, &second);
+    let differs = (0..8)
+        .any(|row| (0..12).any(|col| first.get_pixel(row, col) != other_seed.get_pixel(row, col)));
+    assert!(differs);
+}
+
+#[test]
+fn adaptive_render_without_contrast_matches_trace_world() {
+    let mut expected = Canvas::new(16, 12, None);
+    let mut adaptive = Canvas::new(16, 12, None);
+    let (world, camera) = three_balls_in_a_room::build(16, 12);
+
+    Tracer::trace_world(&world, &camera, &mut expected);
+    let extra_rays = Tracer::trace_world_adaptive(&world, &camera, &mut adaptive, 10., 3);
+
+    assert_eq!(0, extra_rays);
+    assert_canvases_identical(&expected, &adaptive);
+}
+
+#[test]
+fn adaptive_render_keeps_background_of_empty_world() {
+    let background = RgbColor::new(0.25, 0.5, 0.75);
+    let mut canvas = Canvas::new(5, 5, Some(background.clone()));
+    let camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 2.);
+
+    let extra_rays = Tracer::trace_world_adaptive(&World::new(), &camera, &mut canvas, 0., 4);
+
+    assert_eq!(0, extra_rays);
+    assert_eq!(background, canvas.get_pixel(2, 2));
+}
+
+#[test]
+fn adaptive_render_only_refines_high_contrast_pixels() {
+    let world = small_sphere_world();
+    let background = RgbColor::new(0., 0., 1.);
+    let mut canvas = Canvas::new(9, 9, Some(background.clone()));
+    let camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 8.);
+
+    let extra_rays = Tracer::trace_world_adaptive(&world, &camera, &mut canvas, 0.1, 1);
+
+    assert!(extra_rays > 0);
+    assert_eq!(0, extra_rays % 4);
+    assert!(extra_rays < 9 * 9 * 4);
+    assert_eq!(background, canvas.get_pixel(0, 0));
+    assert_ne!(background, canvas.get_pixel(4, 4));
+}
+
+#[test]
+fn adaptive_render_blends_silhouette_pixels() {
+    let world = small_sphere_world();
+    let background = RgbColor::new(0., 0., 1.);
+    let mut single = Canvas::new(9, 9, Some(background.clone()));
+    let mut shallow_canvas = Canvas::new(9, 9, Some(background.clone()));
+    let mut adaptive = Canvas::new(9, 9, Some(background.clone()));
+    let camera = Camera::new(single.get_width(), single.get_height(), PI / 8.);
+
+    Tracer::trace_world(&world, &camera, &mut single);
+    let shallow = Tracer::trace_world_adaptive(&world, &camera, &mut shallow_canvas, 0.1, 1);
+    let deep = Tracer::trace_world_adaptive(&world, &camera, &mut adaptive, 0.1, 4);
+
+    assert!(deep > shallow);
+    let is_partial = |color: RgbColor| color.get_blue() > 0. && color.get_blue() < 1.;
+    let single_partial = (0..9)
+        .flat_map(|row| (0..9).map(move |col| (row, col)))
+        .filter(|(row, col)| is_partial(single.get_pixel(*row, *col)))
+        .count();
+    let adaptive_partial = (0..9)
+        .flat_map(|row| (0..9).map(move |col| (row, col)))
+        .filter(|(row, col)| is_partial(adaptive.get_pixel(*row, *col)))
+        .count();
+    assert_eq!(0, single_partial);
+    assert!(adaptive_partial > 0);
+}
+
+fn total_difference(a: &Canvas, b: &Canvas) -> f64 {
+    let mut total = 0.;
+    for row in 0..a.get_height() {
+        for col in 0..a.get_width() {
+            let difference = &a.get_pixel(row, col) - &b.get_pixel(row, col);
+            total += difference.get_red().abs()
+                + difference.get_green().abs()
+                + difference.get_blue().abs();
+        }
+    }
+    total
+}
+
+#[test]
+fn depth_of_field_blurs_only_out_of_focus_objects() {
+    let world = small_sphere_world();
+    let background = RgbColor::new(0., 0., 1.);
+    let render = |aperture: f64, focal_distance: f64| {
+        let mut canvas = Canvas::new(9, 9, Some(background.clone()));
+        let mut camera = Camera::new(canvas.get_width(), canvas.get_height(), PI / 8.);
+        camera.set_aperture(aperture);
+        camera.set_focal_distance(focal_distance);
+        let mut tracer = Tracer::new();
+        tracer.set_sampler(Sampler::new(SamplePattern::Grid, 16));
+        tracer.render(&world, &camera, &mut canvas);
+        canvas
+    };
+
+    let pinhole = render(0., 1.);
+    let in_focus = render(0.3, 5.);
+    let out_of_focus = render(0.3, 1.);
+
+    assert!(total_difference(&pinhole, &in_focus) < total_difference(&pinhole, &out_of_focus));
+}
+
+fn hit_mask(canvas: &Canvas, background: &RgbColor) -> Vec<bool> {
+    (0..canvas.get_height())
+        .flat_map(|row| (0..canvas.get_width()).map(move |col| (row, col)))
+        .map(|(row, col)| &canvas.get_pixel(row, col) != background)
+        .collect()
+}
+
+#[test]
+fn orthographic_render_does_not_shrink_with_distance() {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(-10., 10., 10.),
+        RgbColor::new(1., 1., 1.),
+    ));
+    world.add_object(Sphere::identity());
+    let background = RgbColor::new(0., 0., 1.);
+    let render = |distance: f64| {
+        let mut canvas = Canvas::new(11, 11, Some(background.clone()));
+        let mut camera = Camera::with_projection(
+            canvas.get_width(),
+            canvas.get_height(),
+            OrthographicProjection::new(4.),
+        );
+        camera.set_position(
+            &Point::new(0., 0., distance),
+            &Point::new(0., 0., 0.),
+            &Vector::new(0., 1., 0.),
+        );
+        Tracer::trace_world(&world, &camera, &mut canvas);
+        canvas
+    };
+
+    let near = hit_mask(&render(5.), &background);
+    let far = hit_mask(&render(50.), &background);
+
+    assert_eq!(near, far);
+    assert!(near[5 * 11 + 5]);
+    assert!(!near[0]);
+}
+
+#[test]
+fn fisheye_render_keeps_background_outside_image_circle() {
+    let world = wall_world();
+    let background = RgbColor::new(0.25, 0.5, 0.75);
+    let mut canvas = Canvas::new(9, 9, Some(background.clone()));
+    let camera = Camera::with_projection(
+        canvas.get_width(),
+        canvas.get_height(),
+        FisheyeProjection::new(PI / 2.),
+    );
+
+    Tracer::trace_world(&world, &camera, &mut canvas);
+
+    assert_eq!(background, canvas.get_pixel(0, 0));
+    assert_eq!(background, canvas.get_pixel(8, 8));
+    assert_ne!(background, canvas.get_pixel(4, 4));
+    assert_ne!(background, canvas.get_pixel(4, 0));
+}
+
+#[test]
+fn equirectangular_render_sees_behind_the_camera() {
+    let mut world = World::new();
+    world.add_light(PointLight::new(
+        Point::new(0., 0., 0.),
+        RgbColor::new(1., 1., 1.),
+    ));
+    world.add_object(Sphere::with_transform(
+        &SquareMatrix::translation(0., 0., -5.) * &SquareMatrix::scaling(2., 2., 2.),
+    ));
+    world.add_object(Sphere::with_transform(
+        &SquareMatrix::translation(0., 0., 5.) * &SquareMatrix::scaling(2., 2., 2.),
+    ));
+    let background = RgbColor::new(0., 0., 1.);
+    let mut canvas = Canvas::new(16, 8, Some(background.clone()));
+    let camera = Camera::with_projection(
+        canvas.get_width(),
+        canvas.get_height(),
+        EquirectangularProjection::new(),
+    );
+
+    Tracer::trace_world_parallel(&world, &camera, &mut canvas, 2, 4, |_, _| {});
+
+    assert_ne!(background, canvas.get_pixel(3, 8));
+    assert_ne!(background, canvas.get_pixel(3, 0));
+    assert_eq!(background, canvas.get_pixel(3, 4));
+}
+
+#[test]
+fn camera_resolution_is_mapped_onto_canvas() {
+    let (world, camera) = three_balls_in_a_room::build(8, 8);
+    let (_, small_camera) = three_balls_in_a_room::build(4, 4);
+    let mut expected = Canvas::new(8, 8, None);
+    let mut serial = Canvas::new(8, 8, None);
+    let mut parallel = Canvas::new(8, 8, None);
+    let mut adaptive = Canvas::new(8, 8, None);
+    let mut adaptive_expected = Canvas::new(8, 8, None);
+
+    Tracer::trace_world(&world, &camera, &mut expected);
+    Tracer::trace_world(&world, &small_camera, &mut serial);
+    Tracer::trace_world_parallel(&world, &small_camera, &mut parallel, 2, 3, |_, _| {});
+    Tracer::trace_world_adaptive(&world, &camera, &mut adaptive_expected, 0.1, 2);
+    Tracer::trace_world_adaptive(&world, &small_camera, &mut adaptive, 0.1, 2);
+
+    for y in 0..8 {
+        for x in 0..8 {
+            assert_eq!(expected.get_pixel(y, x), serial.get_pixel(y, x));
+            assert_eq!(expected.get_pixel(y, x), parallel.get_pixel(y, x));
+            assert_eq!(adaptive_expected.get_pixel(y, x), adaptive.get_pixel(y, x));
+        }
+    }
+    assert_ne!(serial.get_pixel(7, 6), serial.get_pixel(7, 7));
+}
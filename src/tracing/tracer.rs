@@ -1,32 +1,497 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
 use crate::{
-    cameras::Camera,
-    primitives::{Canvas, Ray, RgbColor, World},
+    cameras::{Camera, Sampler},
+    primitives::{Canvas, HitRecord, Ray, RgbColor, World, DEFAULT_EPSILON},
 };
 
-pub struct Tracer;
+const DEFAULT_MAX_DEPTH: usize = 5;
+
+pub struct Tracer {
+    max_depth: usize,
+    epsilon: f64,
+    sampler: Sampler,
+}
+
+/// Sum of the colors of the samples that hit something in one pixel.
+struct PixelSamples {
+    sum: RgbColor,
+    hits: usize,
+    count: usize,
+}
+
+impl PixelSamples {
+    /// Averages the samples, letting misses show the existing background.
+    fn resolve(&self, background: &RgbColor) -> Option<RgbColor> {
+        if self.hits == 0 {
+            return None;
+        }
+        let misses = (self.count - self.hits) as f64;
+        let total = &self.sum + &(background * misses);
+        Some(&total * (1.0 / self.count as f64))
+    }
+}
+
+/// A square part of a pixel, in pixel-relative offsets.
+#[derive(Clone, Copy)]
+struct Region<'a> {
+    x: usize,
+    y: usize,
+    left: f64,
+    top: f64,
+    size: f64,
+    background: &'a RgbColor,
+}
+
+/// A camera seen through the pixels of the canvas being rendered, which may
+/// have a different resolution than the camera itself.
+struct CanvasCamera<'a> {
+    camera: &'a Camera,
+    scale_x: f64,
+    scale_y: f64,
+}
+
+impl<'a> CanvasCamera<'a> {
+    fn new(camera: &'a Camera, canvas: &Canvas) -> Self {
+        CanvasCamera {
+            camera,
+            scale_x: camera.get_width() as f64 / canvas.get_width() as f64,
+            scale_y: camera.get_height() as f64 / canvas.get_height() as f64,
+        }
+    }
+
+    fn get_ray(
+        &self,
+        x: usize,
+        y: usize,
+        offset_x: f64,
+        offset_y: f64,
+        lens_u: f64,
+        lens_v: f64,
+    ) -> Option<Ray> {
+        self.camera.get_lens_ray_at(
+            (x as f64 + offset_x) * self.scale_x,
+            (y as f64 + offset_y) * self.scale_y,
+            lens_u,
+            lens_v,
+        )
+    }
+}
+
+struct Refinement<'a> {
+    world: &'a World,
+    camera: CanvasCamera<'a>,
+    threshold: f64,
+    extra_rays: usize,
+}
+
+struct Tile {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+fn contrast(a: &RgbColor, b: &RgbColor) -> f64 {
+    (a.get_red() - b.get_red())
+        .abs()
+        .max((a.get_green() - b.get_green()).abs())
+        .max((a.get_blue() - b.get_blue()).abs())
+}
 
 impl Tracer {
+    pub fn new() -> Self {
+        Tracer {
+            max_depth: DEFAULT_MAX_DEPTH,
+            epsilon: DEFAULT_EPSILON,
+            sampler: Sampler::default(),
+        }
+    }
+
+    pub fn get_max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
+    pub fn get_epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+    pub fn set_epsilon(&mut self, epsilon: f64) {
+        self.epsilon = epsilon;
+    }
+
+    pub fn get_sampler(&self) -> &Sampler {
+        &self.sampler
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
+
     pub fn trace_world(world: &World, camera: &Camera, canvas: &mut Canvas) {
+        Tracer::new().render(world, camera, canvas);
+    }
+
+    pub fn trace_world_parallel<F: FnMut(usize, usize)>(
+        world: &World,
+        camera: &Camera,
+        canvas: &mut Canvas,
+        threads: usize,
+        tile_size: usize,
+        on_tile_finished: F,
+    ) {
+        Tracer::new().render_parallel(world, camera, canvas, threads, tile_size, on_tile_finished);
+    }
+
+    pub fn trace_world_adaptive(
+        world: &World,
+        camera: &Camera,
+        canvas: &mut Canvas,
+        threshold: f64,
+        max_subdivisions: usize,
+    ) -> usize {
+        Tracer::new().render_adaptive(world, camera, canvas, threshold, max_subdivisions)
+    }
+
+    /// Renders `camera`'s view into `canvas`. A canvas with another resolution
+    /// than the camera is covered by the same view, sampled at its own pixels.
+    pub fn render(&self, world: &World, camera: &Camera, canvas: &mut Canvas) {
+        let camera = CanvasCamera::new(camera, canvas);
         for y in 0..canvas.get_height() {
             for x in 0..canvas.get_width() {
-                let ray = camera.get_ray(x, y);
-                let all_hits = world.get_hits(&ray);
-
-                if let Some(hit) = World::get_first_visible_hit(&all_hits) {
-                    let mut record = hit.get_hit_record(&ray);
-                    let mut color = RgbColor::new(0., 0., 0.);
-                    let origin = ray.at(record.get_t() - 1e-6);
-                    for light in world.get_lights().iter() {
-                        let vec = light.get_location() - &origin;
-                        let ray_to_light = Ray::new(origin.clone(), vec);
-                        record.set_is_in_shadow(Self::check_in_shadow(world, &ray_to_light));
-                        color = &color + &light.shade(&record);
-                    }
+                let samples = self.sample_pixel(world, &camera, x, y);
+                if let Some(color) = samples.resolve(&canvas.get_pixel(y, x)) {
                     canvas.set_pixel(y, x, color);
                 }
             }
         }
-        canvas.correct_colors();
+    }
+
+    pub fn render_parallel<F: FnMut(usize, usize)>(
+        &self,
+        world: &World,
+        camera: &Camera,
+        canvas: &mut Canvas,
+        threads: usize,
+        tile_size: usize,
+        mut on_tile_finished: F,
+    ) {
+        let camera = &CanvasCamera::new(camera, canvas);
+        let tiles = Self::split_into_tiles(canvas.get_width(), canvas.get_height(), tile_size);
+        let next_tile = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..threads.max(1).min(tiles.len()) {
+                let sender = sender.clone();
+                let tiles = &tiles;
+                let next_tile = &next_tile;
+                scope.spawn(move || loop {
+                    let index = next_tile.fetch_add(1, Ordering::Relaxed);
+                    let tile = match tiles.get(index) {
+                        Some(tile) => tile,
+                        None => break,
+                    };
+                    let mut colors = Vec::with_capacity(tile.width * tile.height);
+                    for y in tile.y..tile.y + tile.height {
+                        for x in tile.x..tile.x + tile.width {
+                            colors.push(self.sample_pixel(world, camera, x, y));
+                        }
+                    }
+                    if sender.send((index, colors)).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut finished = 0;
+            for (index, colors) in receiver.iter() {
+                let tile = &tiles[index];
+                let mut colors = colors.into_iter();
+                for y in tile.y..tile.y + tile.height {
+                    for x in tile.x..tile.x + tile.width {
+                        let resolved = colors
+                            .next()
+                            .and_then(|samples| samples.resolve(&canvas.get_pixel(y, x)));
+                        if let Some(color) = resolved {
+                            canvas.set_pixel(y, x, color);
+                        }
+                    }
+                }
+                finished += 1;
+                on_tile_finished(finished, tiles.len());
+            }
+        });
+    }
+
+    /// Renders one ray per pixel, then recursively splits pixels that differ
+    /// from a neighbour by more than `threshold` in any channel into quadrants,
+    /// up to `max_subdivisions` levels deep. Returns the number of rays traced
+    /// beyond the first pass.
+    pub fn render_adaptive(
+        &self,
+        world: &World,
+        camera: &Camera,
+        canvas: &mut Canvas,
+        threshold: f64,
+        max_subdivisions: usize,
+    ) -> usize {
+        let camera = CanvasCamera::new(camera, canvas);
+        let width = canvas.get_width();
+        let height = canvas.get_height();
+        let mut backgrounds = Vec::with_capacity(width * height);
+        let mut colors = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let background = canvas.get_pixel(y, x);
+                colors.push(
+                    camera
+                        .get_ray(x, y, 0.5, 0.5, 0.5, 0.5)
+                        .and_then(|ray| self.color_at(world, &ray))
+                        .unwrap_or_else(|| background.clone()),
+                );
+                backgrounds.push(background);
+            }
+        }
+
+        let mut needs_refinement = vec![false; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                let right = (x + 1 < width).then(|| index + 1);
+                let below = (y + 1 < height).then(|| index + width);
+                for neighbour in [right, below].into_iter().flatten() {
+                    if contrast(&colors[index], &colors[neighbour]) > threshold {
+                        needs_refinement[index] = true;
+                        needs_refinement[neighbour] = true;
+                    }
+                }
+            }
+        }
+
+        let mut refinement = Refinement {
+            world,
+            camera,
+            threshold,
+            extra_rays: 0,
+        };
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                let color = if needs_refinement[index] {
+                    let region = Region {
+                        x,
+                        y,
+                        left: 0.0,
+                        top: 0.0,
+                        size: 1.0,
+                        background: &backgrounds[index],
+                    };
+                    self.refine(&mut refinement, &region, &colors[index], max_subdivisions)
+                } else {
+                    colors[index].clone()
+                };
+                canvas.set_pixel(y, x, color);
+            }
+        }
+        refinement.extra_rays
+    }
+
+    fn refine(
+        &self,
+        refinement: &mut Refinement<'_>,
+        region: &Region<'_>,
+        centre: &RgbColor,
+        remaining: usize,
+    ) -> RgbColor {
+        if remaining == 0 {
+            return centre.clone();
+        }
+        let half = region.size / 2.0;
+        let quadrants: Vec<Region<'_>> = [(0.0, 0.0), (half, 0.0), (0.0, half), (half, half)]
+            .iter()
+            .map(|(left, top)| Region {
+                left: region.left + left,
+                top: region.top + top,
+                size: half,
+                ..*region
+            })
+            .collect();
+        let colors: Vec<RgbColor> = quadrants
+            .iter()
+            .map(|quadrant| {
+                refinement
+                    .camera
+                    .get_ray(
+                        region.x,
+                        region.y,
+                        quadrant.left + quadrant.size / 2.0,
+                        quadrant.top + quadrant.size / 2.0,
+                        0.5,
+                        0.5,
+                    )
+                    .and_then(|ray| self.color_at(refinement.world, &ray))
+                    .unwrap_or_else(|| region.background.clone())
+            })
+            .collect();
+        refinement.extra_rays += quadrants.len();
+
+        let threshold = refinement.threshold;
+        let mut sum = RgbColor::new(0., 0., 0.);
+        for (quadrant, color) in quadrants.iter().zip(colors.iter()) {
+            let differs = contrast(color, centre) > threshold
+                || colors
+                    .iter()
+                    .any(|sibling| contrast(color, sibling) > threshold);
+            let refined = if differs {
+                self.refine(refinement, quadrant, color, remaining - 1)
+            } else {
+                color.clone()
+            };
+            sum = &sum + &refined;
+        }
+        &sum * 0.25
+    }
+
+    pub fn color_at(&self, world: &World, ray: &Ray) -> Option<RgbColor> {
+        self.color_at_depth(world, ray, self.max_depth)
+    }
+
+    fn sample_pixel(
+        &self,
+        world: &World,
+        camera: &CanvasCamera<'_>,
+        x: usize,
+        y: usize,
+    ) -> PixelSamples {
+        let offsets = self.sampler.get_offsets(x, y);
+        let lens_offsets = if camera.camera.get_aperture() > 0.0 {
+            self.sampler.get_lens_offsets(x, y)
+        } else {
+            vec![(0.5, 0.5); offsets.len()]
+        };
+        let mut samples = PixelSamples {
+            sum: RgbColor::new(0., 0., 0.),
+            hits: 0,
+            count: offsets.len(),
+        };
+        for ((offset_x, offset_y), (lens_u, lens_v)) in offsets.into_iter().zip(lens_offsets) {
+            let color = camera
+                .get_ray(x, y, offset_x, offset_y, lens_u, lens_v)
+                .and_then(|ray| self.color_at(world, &ray));
+            if let Some(color) = color {
+                samples.sum = &samples.sum + &color;
+                samples.hits += 1;
+            }
+        }
+        samples
+    }
+
+    fn split_into_tiles(width: usize, height: usize, tile_size: usize) -> Vec<Tile> {
+        let tile_size = tile_size.max(1);
+        let mut tiles = Vec::new();
+        for y in (0..height).step_by(tile_size) {
+            for x in (0..width).step_by(tile_size) {
+                tiles.push(Tile {
+                    x,
+                    y,
+                    width: tile_size.min(width - x),
+                    height: tile_size.min(height - y),
+                });
+            }
+        }
+        tiles
+    }
+
+    fn color_at_depth(&self, world: &World, ray: &Ray, remaining: usize) -> Option<RgbColor> {
+        let all_hits = world.get_hits(ray);
+
+        let hit = World::get_first_visible_hit(&all_hits)?;
+        let mut record = HitRecord::with_epsilon(hit, ray, &all_hits, self.epsilon);
+        let mut color = RgbColor::new(0., 0., 0.);
+        for light in world.get_lights().iter() {
+            let origin = record.get_over_point();
+            let vec = light.get_location() - origin;
+            let ray_to_light = Ray::new(origin.clone(), vec);
+            record.set_is_in_shadow(Self::check_in_shadow(world, &ray_to_light));
+            color = &color + &light.shade(&record);
+        }
+
+        let reflected = self.reflected_color(world, ray, &record, remaining);
+        let refracted = self.refracted_color(world, &record, remaining);
+        let material = record.get_object().get_material();
+        if material.get_reflective() > 0.0 && material.get_transparency() > 0.0 {
+            let reflectance = Self::schlick(&record);
+            color = &color + &(&reflected * reflectance);
+            color = &color + &(&refracted * (1.0 - reflectance));
+        } else {
+            color = &color + &reflected;
+            color = &color + &refracted;
+        }
+        Some(color)
+    }
+
+    fn reflected_color(
+        &self,
+        world: &World,
+        ray: &Ray,
+        record: &HitRecord,
+        remaining: usize,
+    ) -> RgbColor {
+        let reflective = record.get_object().get_material().get_reflective();
+        if reflective <= 0.0 || remaining == 0 {
+            return RgbColor::new(0., 0., 0.);
+        }
+        let reflected = ray.get_direction().reflect(record.get_normal());
+        let reflected_ray = Ray::new(record.get_over_point().clone(), reflected);
+        match self.color_at_depth(world, &reflected_ray, remaining - 1) {
+            Some(color) => &color * reflective,
+            None => RgbColor::new(0., 0., 0.),
+        }
+    }
+
+    fn refracted_color(&self, world: &World, record: &HitRecord, remaining: usize) -> RgbColor {
+        let transparency = record.get_object().get_material().get_transparency();
+        if transparency <= 0.0 || remaining == 0 {
+            return RgbColor::new(0., 0., 0.);
+        }
+        let (n1, n2) = (record.get_n1(), record.get_n2());
+        let n_ratio = n1 / n2;
+        let cos_i = record.get_eye_vector() * record.get_normal();
+        let sin2_t = n_ratio * n_ratio * (1.0 - cos_i * cos_i);
+        if sin2_t > 1.0 {
+            return RgbColor::new(0., 0., 0.);
+        }
+        let cos_t = (1.0 - sin2_t).sqrt();
+        let direction = &(record.get_normal() * (n_ratio * cos_i - cos_t))
+            - &(record.get_eye_vector() * n_ratio);
+        let refracted_ray = Ray::new(record.get_under_point().clone(), direction);
+        match self.color_at_depth(world, &refracted_ray, remaining - 1) {
+            Some(color) => &color * transparency,
+            None => RgbColor::new(0., 0., 0.),
+        }
+    }
+
+    fn schlick(record: &HitRecord) -> f64 {
+        let (n1, n2) = (record.get_n1(), record.get_n2());
+        let mut cos = record.get_eye_vector() * record.get_normal();
+        if n1 > n2 {
+            let n_ratio = n1 / n2;
+            let sin2_t = n_ratio * n_ratio * (1.0 - cos * cos);
+            if sin2_t > 1.0 {
+                return 1.0;
+            }
+            cos = (1.0 - sin2_t).sqrt();
+        }
+        let r0 = ((n1 - n2) / (n1 + n2)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cos).powi(5)
     }
 
     fn check_in_shadow(world: &World, ray: &Ray) -> bool {
@@ -39,3 +504,13 @@ impl Tracer {
         false
     }
 }
+
+impl Default for Tracer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/tracer_tests.rs"]
+mod tests;